@@ -4,7 +4,9 @@ use bevy::{
     prelude::*,
     window::WindowResized,
 };
-use bevy_third_person_camera_2::{self as tp_cam, SetLocalCamera, ThirdPersonCameraSettings};
+use bevy_third_person_camera_2::{
+    self as tp_cam, CameraGizmosConfig, SetLocalCamera, ThirdPersonCameraSettings,
+};
 
 #[derive(Component)]
 struct MyCube;
@@ -22,11 +24,21 @@ fn main() {
         .add_plugins(tp_cam::ThirdPersonCameraPlugin::new(
             ThirdPersonCameraSettings {
                 show_relation_gizmo: true,
+                zoom_limits: Some((5.0, 30.0)),
                 ..default()
             },
         ))
+        .insert_resource(CameraGizmosConfig {
+            target_points: true,
+            target_offset: true,
+            zoom_range: true,
+            ..default()
+        })
         .add_systems(Startup, spawn_cube_and_camera_s)
-        .add_systems(Update, (move_cube_s, swap_camera_s, set_viewports_s))
+        .add_systems(
+            Update,
+            (move_cube_s, swap_camera_s, set_viewports_s, toggle_gizmos_s),
+        )
         .run();
 }
 
@@ -68,10 +80,14 @@ fn spawn_cube_and_camera_s(
                 ..default()
             },
             Camera3d::default(),
-            Transform::from_translation(Vec3::new(-10.0, 10.0, 10.0))
-                .looking_at(Vec3::ZERO, Dir3::Y),
+            Transform::default(),
             // Targeting to cube
             tp_cam::ThirdPersonCamera::aimed_at(cube),
+            // Initial pose relative to the cube
+            tp_cam::InitialRotation {
+                yaw: -45f32.to_radians(),
+                pitch: -35f32.to_radians(),
+            },
         ))
         .id();
 
@@ -83,10 +99,14 @@ fn spawn_cube_and_camera_s(
                 ..default()
             },
             Camera3d::default(),
-            Transform::from_translation(Vec3::new(10.0, 10.0, 10.0))
-                .looking_at(Vec3::ZERO, Dir3::Y),
+            Transform::default(),
             // Targeting to cube
             tp_cam::ThirdPersonCamera::aimed_at(cube),
+            // Initial pose relative to the cube
+            tp_cam::InitialRotation {
+                yaw: 45f32.to_radians(),
+                pitch: -35f32.to_radians(),
+            },
         ))
         .id();
 
@@ -156,3 +176,11 @@ fn move_cube_s(
         }
     }
 }
+
+/// Toggle the orbit sphere and the pitch limits of the cameras
+fn toggle_gizmos_s(keys: Res<ButtonInput<KeyCode>>, mut gizmos_config: ResMut<CameraGizmosConfig>) {
+    if keys.just_pressed(KeyCode::KeyG) {
+        gizmos_config.orbit_sphere = !gizmos_config.orbit_sphere;
+        gizmos_config.pitch_limits = !gizmos_config.pitch_limits;
+    }
+}
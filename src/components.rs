@@ -1,27 +1,195 @@
 use bevy::prelude::*;
 
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
 /// Position of the camera relative to the target point.
-#[derive(Component)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 pub struct CameraOffset(pub Vec3);
 
 /// Shift the target point relative to the target coordinates.
 /// For example, if the character's coordinate point is at the feet,
 /// shift the rotation point, Y-wise, to the character's chest.
-#[derive(Component)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 pub struct TargetOffset(pub Vec3);
 
 /// Delays camera tracking if inserted. The lower the value, the greater the delay.
-#[derive(Component)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 pub struct DampingFactor(pub f32);
 
-/// Calculated from target position, TargetOffset and DampingFactor
-#[derive(Component)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum DampingSpace {
+    #[default]
+    World,
+    /// Axes of the target's rotation
+    TargetLocal,
+}
+
+/// `DampingFactor` per axis, replaces it when inserted.
+/// For example `Vec3::new(f32::INFINITY, 2.0, f32::INFINITY)` follows jumps lazily
+/// and horizontal movement without delay.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct AxisDamping {
+    pub factors: Vec3,
+    pub space: DampingSpace,
+}
+
+/// Shifts the target point in the direction the target travels.
+/// The velocity is estimated from the target's movement, or read from its `TargetVelocity`.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct LookAhead {
+    /// Seconds of travel to look ahead
+    pub time: f32,
+    pub max_distance: f32,
+    /// The higher the value, the faster the look-ahead follows velocity changes
+    pub smoothing: f32,
+    /// Current shift of the target point
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) offset: Vec3,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) velocity: VelocityEstimate,
+}
+
+impl Default for LookAhead {
+    fn default() -> Self {
+        Self::new(0.5, 3.0)
+    }
+}
+
+impl LookAhead {
+    pub fn new(time: f32, max_distance: f32) -> Self {
+        Self {
+            time,
+            max_distance,
+            smoothing: 3.0,
+            offset: Vec3::ZERO,
+            velocity: VelocityEstimate::default(),
+        }
+    }
+}
+
+/// Velocity of the target, estimated from its movement between frames
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct VelocityEstimate {
+    last_position: Option<Vec3>,
+}
+
+impl VelocityEstimate {
+    /// `TargetVelocity` when there is one, None until the target has been seen twice
+    pub(crate) fn update(
+        &mut self,
+        target_translation: Vec3,
+        velocity_op: Option<&TargetVelocity>,
+        delta_secs: f32,
+    ) -> Option<Vec3> {
+        let last_position = self.last_position.replace(target_translation);
+        match (velocity_op, last_position) {
+            (Some(velocity), _) => Some(velocity.0),
+            (None, Some(last_position)) if delta_secs > 0.0 => {
+                Some((target_translation - last_position) / delta_secs)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Velocity of the target for `LookAhead`, in units per second
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TargetVelocity(pub Vec3);
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum FramingSpace {
+    #[default]
+    World,
+    /// Camera axes: X to the right, Y up, Z along the view
+    Screen,
+}
+
+/// Target movement inside the dead zone around the `TargetPoint` doesn't move the camera.
+/// In the soft zone the target point catches up with `damping`,
+/// the target never leaves the soft zone. Zones are half extents per axis.
+/// Replaces `DampingFactor` when inserted.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct FramingZones {
+    pub space: FramingSpace,
+    pub dead_zone: Vec3,
+    pub soft_zone: Vec3,
+    /// Catch up speed in the soft zone, as `DampingFactor`
+    pub damping: f32,
+}
+
+impl Default for FramingZones {
+    fn default() -> Self {
+        Self {
+            space: FramingSpace::World,
+            dead_zone: Vec3::new(0.5, 0.5, 0.5),
+            soft_zone: Vec3::new(2.0, 2.0, 2.0),
+            damping: 5.0,
+        }
+    }
+}
+
+/// Calculated from target position, TargetOffset, damping and FramingZones
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 pub struct TargetPoint(pub Vec3);
 
-#[derive(Component)]
+/// Color of the debug gizmos of this camera
+#[cfg(feature = "draw_relation_lines")]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+pub struct CameraGizmoColor(pub Color);
+
+/// Camera orientation (in radians) applied once the target point is known.
+/// Without it the camera keeps the rotation it was spawned with.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct InitialRotation {
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, FromWorld)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
 #[relationship(relationship_target = ThirdPersonCameraTarget)]
 pub struct ThirdPersonCamera {
     #[relationship]
+    #[entities]
     pub target: Entity,
 }
 
@@ -31,6 +199,15 @@ impl ThirdPersonCamera {
     }
 }
 
-#[derive(Component)]
+// Reflection deserializes by patching a default instance,
+// the placeholder is replaced with the real target right after.
+impl FromWorld for ThirdPersonCamera {
+    fn from_world(_world: &mut World) -> Self {
+        Self::aimed_at(Entity::PLACEHOLDER)
+    }
+}
+
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component, Debug, Default, FromWorld)]
 #[relationship_target(relationship = ThirdPersonCamera)]
 pub struct ThirdPersonCameraTarget(Vec<Entity>);
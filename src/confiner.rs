@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// World space limits of a `CameraConfiner`
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum ConfinerShape {
+    Aabb {
+        min: Vec3,
+        max: Vec3,
+    },
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    /// Convex polygon on the XZ plane, extruded from `min_y` to `max_y`
+    ExtrudedPolygon {
+        points: Vec<Vec2>,
+        min_y: f32,
+        max_y: f32,
+    },
+}
+
+impl ConfinerShape {
+    /// Closest point inside the shape, the point itself if it's already inside
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        match self {
+            Self::Aabb { min, max } => point.max(*min).min(*max),
+            Self::Sphere { center, radius } => *center + (point - center).clamp_length_max(*radius),
+            Self::ExtrudedPolygon {
+                points,
+                min_y,
+                max_y,
+            } => {
+                let xz = closest_in_polygon(points, point.xz());
+                Vec3::new(xz.x, point.y.max(*min_y).min(*max_y), xz.y)
+            }
+        }
+    }
+}
+
+/// Either winding order
+fn closest_in_polygon(points: &[Vec2], point: Vec2) -> Vec2 {
+    let edges = || points.iter().zip(points.iter().cycle().skip(1));
+    let sides = edges().map(|(a, b)| (b - a).perp_dot(point - a));
+    let (mut inner, mut outer) = (true, true);
+    for side in sides {
+        inner &= side >= 0.0;
+        outer &= side <= 0.0;
+    }
+    if points.len() < 3 || inner || outer {
+        return point;
+    }
+
+    edges()
+        .map(|(a, b)| {
+            let edge = b - a;
+            let s = ((point - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0);
+            a + edge * s
+        })
+        .min_by(|p1, p2| {
+            point
+                .distance_squared(*p1)
+                .total_cmp(&point.distance_squared(*p2))
+        })
+        .unwrap_or(point)
+}
+
+/// Keeps the camera inside the shape, it slides along the boundary when the orbit goes outside.
+/// The camera turns to keep the target point where it was on screen.
+/// Confines the final pose, after the modes, volumes, rails and blends.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[require(OrbitOverride)]
+pub struct CameraConfiner {
+    pub shape: ConfinerShape,
+    /// The higher the value, the faster the camera is pushed back inside, `None` to confine at once
+    pub smoothing: Option<f32>,
+    /// Shift from the unconfined position to the confined one
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) correction: Vec3,
+}
+
+impl CameraConfiner {
+    pub fn new(shape: ConfinerShape) -> Self {
+        Self {
+            shape,
+            smoothing: Some(10.0),
+            correction: Vec3::ZERO,
+        }
+    }
+
+    /// Shift needed to bring `translation` inside the shape
+    pub(crate) fn required_correction(&self, translation: Vec3) -> Vec3 {
+        self.shape.closest_point(translation) - translation
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Entity the cameras can switch to with the `CycleTarget` event
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct SpectatorTarget {
+    /// Higher first with `CycleOrder::Priority`
+    pub priority: i32,
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum CycleOrder {
+    /// Closest to `TargetCycling::origin` first
+    #[default]
+    Distance,
+    /// Highest `SpectatorTarget::priority` first
+    Priority,
+}
+
+/// How the camera cycles through the `SpectatorTarget` entities, defaults apply without it
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Debug, Clone, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct TargetCycling {
+    pub order: CycleOrder,
+    /// Duration of the transition to the new target, in seconds
+    pub blend_duration: f32,
+    /// Only the targets for which it returns true are eligible
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub filter: Option<fn(EntityRef) -> bool>,
+    /// Point the distances are measured from, set to the camera position on the first cycle.
+    /// Reset it to order the targets from the current position.
+    pub origin: Option<Vec3>,
+}
+
+impl Default for TargetCycling {
+    fn default() -> Self {
+        Self {
+            order: CycleOrder::Distance,
+            blend_duration: 0.5,
+            filter: None,
+            origin: None,
+        }
+    }
+}
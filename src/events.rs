@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::{mode::CameraMode, sequence::CameraSequence, visibility::TargetVisibility};
+
 /// Assign the camera to be controlled locally
 #[derive(Debug, EntityEvent)]
 pub struct SetLocalCamera(pub Entity);
@@ -33,3 +35,156 @@ pub struct Roll {
     pub camera: Entity,
     pub value: f32,
 }
+
+/// Triggered when the camera's target is despawned
+#[derive(Debug, EntityEvent)]
+pub struct TargetLost {
+    #[event_target]
+    pub camera: Entity,
+    pub target: Entity,
+}
+
+/// Triggered after the camera has rotated around the target
+#[derive(Debug, EntityEvent)]
+pub struct CameraOrbited {
+    #[event_target]
+    pub camera: Entity,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+/// Triggered after the camera has zoomed
+#[derive(Debug, EntityEvent)]
+pub struct CameraZoomed {
+    #[event_target]
+    pub camera: Entity,
+    /// Distance between the camera and the target point
+    pub distance: f32,
+}
+
+/// Triggered after a `Follow2d` camera has zoomed
+#[derive(Debug, EntityEvent)]
+pub struct CameraScaled {
+    #[event_target]
+    pub camera: Entity,
+    /// Orthographic scale of the projection
+    pub scale: f32,
+}
+
+/// Triggered when an `Occluder` comes between the camera and its target point
+#[derive(Debug, EntityEvent)]
+pub struct CameraCollided {
+    #[event_target]
+    pub camera: Entity,
+    pub occluder: Entity,
+}
+
+/// Triggered after the camera has rolled
+#[derive(Debug, EntityEvent)]
+pub struct CameraRolled {
+    #[event_target]
+    pub camera: Entity,
+    pub roll: f32,
+}
+
+/// Triggered when the camera is aimed at a target, including the first one
+#[derive(Debug, EntityEvent)]
+pub struct TargetChanged {
+    #[event_target]
+    pub camera: Entity,
+    pub target: Entity,
+}
+
+/// Play a camera sequence, the player's orbit controls are ignored until it's finished
+#[derive(Debug, EntityEvent)]
+pub struct PlaySequence {
+    #[event_target]
+    pub camera: Entity,
+    pub sequence: CameraSequence,
+}
+
+/// Pause or resume the camera sequence
+#[derive(Debug, EntityEvent)]
+pub struct PauseSequence {
+    #[event_target]
+    pub camera: Entity,
+    pub paused: bool,
+}
+
+/// Stop the camera sequence and return to the player's orbit state
+#[derive(Debug, EntityEvent)]
+pub struct SkipSequence {
+    #[event_target]
+    pub camera: Entity,
+}
+
+/// Triggered when the camera is back to the player's orbit state
+#[derive(Debug, EntityEvent)]
+pub struct SequenceFinished {
+    #[event_target]
+    pub camera: Entity,
+    pub skipped: bool,
+}
+
+/// Triggered when the `InputReplay` of the camera has triggered all its events
+#[derive(Debug, EntityEvent)]
+pub struct ReplayFinished {
+    #[event_target]
+    pub camera: Entity,
+}
+
+/// Detach the camera from its target to fly freely, see `FreeFly`
+#[derive(Debug, EntityEvent)]
+pub struct DetachCamera {
+    #[event_target]
+    pub camera: Entity,
+}
+
+/// Aim the camera at `target` and blend to its orbit pose during `blend_duration` seconds.
+/// Ends the free flight of a detached camera.
+#[derive(Debug, EntityEvent)]
+pub struct AttachCamera {
+    #[event_target]
+    pub camera: Entity,
+    pub target: Entity,
+    pub blend_duration: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CycleDirection {
+    Next,
+    Previous,
+}
+
+/// Switch the camera to the next or previous `SpectatorTarget`, ordered by `TargetCycling`
+#[derive(Debug, EntityEvent)]
+pub struct CycleTarget {
+    #[event_target]
+    pub camera: Entity,
+    pub direction: CycleDirection,
+}
+
+/// Triggered when the `TargetVisibility` of the camera changes
+#[derive(Debug, EntityEvent)]
+pub struct TargetVisibilityChanged {
+    #[event_target]
+    pub camera: Entity,
+    pub visibility: TargetVisibility,
+}
+
+/// Switch the camera to `mode`, blending from the current pose during `transition` seconds
+#[derive(Debug, EntityEvent)]
+pub struct SetCameraMode {
+    #[event_target]
+    pub camera: Entity,
+    pub mode: CameraMode,
+    pub transition: f32,
+}
+
+/// Triggered when the camera has switched to another mode
+#[derive(Debug, EntityEvent)]
+pub struct CameraModeChanged {
+    #[event_target]
+    pub camera: Entity,
+    pub mode: CameraMode,
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Follows the target in the XY plane instead of orbiting around it, for 2D cameras.
+/// The camera keeps its Z and rotation, `CameraOffset` and rotation controls are ignored.
+/// Damping and dead zones come from `DampingFactor`, `AxisDamping` and `FramingZones`,
+/// the `Zoom` event scales the orthographic projection and triggers `CameraScaled`.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct Follow2d {
+    /// Relative scale change per unit of `Zoom`
+    pub zoom_speed: f32,
+    /// Min and max orthographic scale
+    pub scale_limits: Option<(f32, f32)>,
+    /// World area the view is kept inside of
+    pub bounds: Option<Rect>,
+}
+
+impl Default for Follow2d {
+    fn default() -> Self {
+        Self {
+            zoom_speed: 0.1,
+            scale_limits: None,
+            bounds: None,
+        }
+    }
+}
+
+impl Follow2d {
+    /// Camera translation centered on the target point, the view kept inside the `bounds`
+    pub(crate) fn translation(
+        &self,
+        target_point: Vec3,
+        z: f32,
+        projection_op: Option<&Projection>,
+    ) -> Vec3 {
+        let mut center = target_point.truncate();
+        if let Some(bounds) = self.bounds {
+            let view_half_size = match projection_op {
+                Some(Projection::Orthographic(orthographic)) => orthographic.area.half_size(),
+                _ => Vec2::ZERO,
+            };
+            let min = bounds.min + view_half_size;
+            let max = bounds.max - view_half_size;
+            // A view larger than the bounds stays centered on them
+            center = Vec2::select(min.cmple(max), center.max(min).min(max), bounds.center());
+        }
+        center.extend(z)
+    }
+
+    /// Orthographic scale after zooming by `value`
+    pub(crate) fn zoomed_scale(&self, scale: f32, value: f32) -> f32 {
+        let scale = scale * (-value * self.zoom_speed).exp();
+        self.scale_limits.map_or(scale, |(scale_min, scale_max)| {
+            scale.clamp(scale_min, scale_max)
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Camera detached from its target and flying freely, inserted by the `DetachCamera` event.
+/// The mouse and the rotation keys still turn the camera, zoom is ignored.
+/// The local camera moves with the keys below, relative to where it looks.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct FreeFly {
+    /// Units per second
+    pub speed: f32,
+    pub forward: KeyCode,
+    pub back: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub ascend: KeyCode,
+    pub descend: KeyCode,
+}
+
+impl Default for FreeFly {
+    fn default() -> Self {
+        Self {
+            speed: 10.0,
+            forward: KeyCode::KeyW,
+            back: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            ascend: KeyCode::Space,
+            descend: KeyCode::ShiftLeft,
+        }
+    }
+}
+
+/// Smooth transition from `from` to the camera pose,
+/// inserted by the `AttachCamera` and `SetCameraMode` events.
+/// Removed at the end of the transition.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[require(OrbitOverride)]
+pub struct AttachBlend {
+    pub from: Transform,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl AttachBlend {
+    pub fn new(from: Transform, duration: f32) -> Self {
+        Self {
+            from,
+            elapsed: 0.0,
+            duration,
+        }
+    }
+}
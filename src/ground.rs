@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Height of the ground surface, implemented for closures.
+/// A physics engine can answer it with a downward ray cast.
+pub trait GroundHeight: Send + Sync + 'static {
+    /// Height of the surface beneath `position` (X and Z), None without ground
+    fn height_at(&self, position: Vec2) -> Option<f32>;
+}
+
+impl<F> GroundHeight for F
+where
+    F: Fn(Vec2) -> Option<f32> + Send + Sync + 'static,
+{
+    fn height_at(&self, position: Vec2) -> Option<f32> {
+        self(position)
+    }
+}
+
+/// Ground used by the cameras with `GroundClearance`
+#[derive(Resource)]
+pub struct GroundHeightQuery(pub Box<dyn GroundHeight>);
+
+impl GroundHeightQuery {
+    pub fn new(ground: impl GroundHeight) -> Self {
+        Self(Box::new(ground))
+    }
+}
+
+/// Keeps the camera `min_height` above the ground by looking further down.
+/// The player's pitch is left untouched and comes back once the camera is clear.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[require(OrbitOverride)]
+pub struct GroundClearance {
+    pub min_height: f32,
+    /// Radians per second to return to the player's pitch, the camera is raised at once
+    pub restore_speed: f32,
+    /// Pitch added below the player's pitch, in radians
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) pitch_offset: f32,
+}
+
+impl Default for GroundClearance {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl GroundClearance {
+    pub fn new(min_height: f32) -> Self {
+        Self {
+            min_height,
+            restore_speed: 2.0,
+            pitch_offset: 0.0,
+        }
+    }
+}
@@ -1,19 +1,71 @@
 use bevy::prelude::*;
 
 pub use components::*;
+pub use confiner::*;
+pub use cycling::*;
 pub use events::*;
+pub use follow_2d::*;
+pub use free_fly::*;
+pub use ground::*;
+pub use mode::*;
+pub use network::*;
+pub use occlusion::*;
+#[cfg(feature = "draw_relation_lines")]
+pub use plugin_settings::CameraGizmosConfig;
+#[cfg(feature = "debug_overlay")]
+pub use plugin_settings::DebugOverlayConfig;
 pub use plugin_settings::ThirdPersonCameraSettings;
-use systems::base::*;
+#[cfg(feature = "camera_profile")]
+pub use profile::*;
+pub use rail::*;
+pub use recording::*;
+pub use sequence::*;
+pub use speed::*;
+use systems::{
+    base::*, confiner::*, follow_2d::*, free_fly::*, ground::*, mode::*, network::*, occlusion::*,
+    rail::*, recording::*, sequence::*, speed::*, visibility::*, volumes::*,
+};
+pub use target_loss::*;
+pub use visibility::*;
+pub use volumes::*;
 
 #[cfg(feature = "draw_relation_lines")]
 use systems::gizmo_lines::*;
 
+#[cfg(feature = "debug_overlay")]
+use systems::debug_overlay::*;
+
+#[cfg(feature = "camera_profile")]
+use systems::profile::*;
+
 mod components;
+mod confiner;
+mod cycling;
 mod events;
+mod follow_2d;
+mod free_fly;
+mod ground;
+mod math;
+mod mode;
+mod network;
 mod observers;
+mod occlusion;
+mod orbit_override;
 mod plugin_settings;
+#[cfg(feature = "camera_profile")]
+mod profile;
+mod rail;
+mod recording;
+mod sequence;
+mod speed;
 mod systems;
+mod target_loss;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod visibility;
+mod volumes;
 
+#[derive(Default)]
 pub struct ThirdPersonCameraPlugin {
     settings: ThirdPersonCameraSettings,
 }
@@ -24,26 +76,85 @@ impl ThirdPersonCameraPlugin {
     }
 }
 
-impl Default for ThirdPersonCameraPlugin {
-    fn default() -> Self {
-        Self {
-            settings: ThirdPersonCameraSettings::default(),
-        }
-    }
-}
-
 impl Plugin for ThirdPersonCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(self.settings.clone())
+        app.register_type::<ThirdPersonCameraSettings>()
+            .register_type::<ThirdPersonCamera>()
+            .register_type::<ThirdPersonCameraTarget>()
+            .register_type::<CameraOffset>()
+            .register_type::<TargetOffset>()
+            .register_type::<DampingFactor>()
+            .register_type::<AxisDamping>()
+            .register_type::<TargetPoint>()
+            .register_type::<LookAhead>()
+            .register_type::<FramingZones>()
+            .register_type::<SpeedEffects>()
+            .register_type::<CameraVolume>()
+            .register_type::<VolumeBlend>()
+            .register_type::<CameraMode>()
+            .register_type::<Follow2d>()
+            .register_type::<CameraConfiner>()
+            .register_type::<TargetVelocity>()
+            .register_type::<InitialRotation>()
+            .register_type::<CameraRail>()
+            .register_type::<FreeFly>()
+            .register_type::<AttachBlend>()
+            .register_type::<SpectatorTarget>()
+            .register_type::<TargetCycling>()
+            .register_type::<GroundClearance>()
+            .register_type::<Occluder>()
+            .register_type::<OccludingTarget>()
+            .register_type::<OccluderFade>()
+            .register_type::<TargetVisibility>()
+            .register_type::<SequencePlayer>()
+            .register_type::<InputRecorder>()
+            .register_type::<InputReplay>()
+            .register_type::<SnapshotBuffer>()
+            .register_type::<NetworkTargetId>()
+            .register_type::<TargetLossPolicy>()
+            .insert_resource(self.settings)
+            .init_resource::<OccluderFade>()
             .add_observer(observers::rotate_camera_o)
             .add_observer(observers::adjust_translation_o)
             .add_observer(observers::set_local_cam_o)
             .add_observer(observers::roll_camera_o)
             .add_observer(observers::zoom_o)
-            .add_systems(PreUpdate, spawn_components_s)
+            .add_observer(observers::target_changed_o)
+            .add_observer(observers::camera_detached_o)
+            .add_observer(observers::target_lost_o)
+            .add_observer(observers::orbit_override_removed_o)
+            .add_observer(observers::detach_camera_o)
+            .add_observer(observers::attach_camera_o)
+            .add_observer(observers::cycle_target_o)
+            .add_observer(observers::set_camera_mode_o)
+            .add_observer(observers::play_sequence_o)
+            .add_observer(observers::pause_sequence_o)
+            .add_observer(observers::skip_sequence_o)
+            .add_observer(observers::recording_started_o)
+            .add_observer(observers::replay_started_o)
+            .add_observer(observers::record_rotate_o)
+            .add_observer(observers::record_zoom_o)
+            .add_observer(observers::record_roll_o)
+            .add_observer(observers::record_set_local_cam_o)
+            .add_systems(
+                PreUpdate,
+                (
+                    restore_orbit_override_s,
+                    spawn_components_s,
+                    init_target_point_s,
+                )
+                    .chain(),
+            )
             .add_systems(
                 Update,
-                (calculate_target_point_s, adjust_translation_after_target_s),
+                (
+                    play_sequence_s,
+                    apply_snapshots_s,
+                    calculate_target_point_s,
+                    adjust_translation_after_target_s,
+                    adjust_2d_view_s,
+                )
+                    .chain(),
             )
             .add_systems(
                 Update,
@@ -51,10 +162,52 @@ impl Plugin for ThirdPersonCameraPlugin {
                     mouse_rotation_control_s,
                     keyboard_rotation_control_s,
                     scroll_zoom_s,
+                    replay_input_s,
+                    free_fly_s,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    (
+                        apply_speed_effects_s,
+                        apply_camera_volumes_s,
+                        apply_camera_mode_s,
+                        follow_rail_s,
+                        blend_attach_s,
+                        confine_cameras_s,
+                        // Last safety constraint on the final pose
+                        ground_clearance_s,
+                    )
+                        .chain()
+                        .before(TransformSystems::Propagate),
+                    (
+                        (detect_occluders_s, fade_occluders_s).chain(),
+                        update_target_visibility_s,
+                    )
+                        .after(TransformSystems::Propagate),
                 ),
             );
 
+        #[cfg(feature = "camera_profile")]
+        app.register_type::<SettingsProfile>()
+            .register_type::<CameraProfile>()
+            .init_asset::<ThirdPersonCameraProfile>()
+            .init_asset_loader::<ThirdPersonCameraProfileLoader>()
+            .add_systems(Update, (apply_settings_profile_s, apply_camera_profile_s));
+
         #[cfg(feature = "draw_relation_lines")]
-        app.add_systems(Update, draw_relation_gizmo);
+        app.register_type::<CameraGizmosConfig>()
+            .register_type::<CameraGizmoColor>()
+            .init_resource::<CameraGizmosConfig>()
+            .add_systems(Update, draw_relation_gizmo_s);
+
+        #[cfg(feature = "debug_overlay")]
+        app.register_type::<DebugOverlayConfig>()
+            .init_resource::<DebugOverlayConfig>()
+            .add_systems(
+                Update,
+                (toggle_debug_overlay_s, draw_debug_overlay_s).chain(),
+            );
     }
 }
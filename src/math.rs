@@ -0,0 +1,25 @@
+use core::f32::consts::{PI, TAU};
+
+/// Interpolation along the shortest arc
+pub(crate) fn lerp_angle(from: f32, to: f32, s: f32) -> f32 {
+    let delta = (to - from + PI).rem_euclid(TAU) - PI;
+    from + delta * s
+}
+
+/// Angle clamped to the arc going counterclockwise from `min` to `max`,
+/// which may cross ±π, for example `(3.0, -3.0)`.
+/// Outside of the arc the closest limit is returned.
+pub(crate) fn clamp_angle(angle: f32, min: f32, max: f32) -> f32 {
+    if max - min >= TAU {
+        return angle;
+    }
+    let width = (max - min).rem_euclid(TAU);
+    let from_min = (angle - min).rem_euclid(TAU);
+    if from_min <= width {
+        angle
+    } else if from_min - width < TAU - from_min {
+        max
+    } else {
+        min
+    }
+}
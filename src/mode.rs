@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// How the camera is placed, changed with a transition by the `SetCameraMode` event.
+/// The orbit controls keep working in every mode and take over in `Orbit`.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[require(OrbitOverride)]
+pub enum CameraMode {
+    #[default]
+    Orbit,
+    /// Stays at `position` and looks at the target point
+    Fixed { position: Vec3 },
+    /// Follows the target on the orbit and looks at `point`
+    LookAtPoint { point: Vec3 },
+}
+
+impl CameraMode {
+    /// Short name of the mode, as shown by the debug overlay
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Orbit => "orbit",
+            Self::Fixed { .. } => "fixed",
+            Self::LookAtPoint { .. } => "look at point",
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{components, math::lerp_angle};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Id of a target shared by all the peers, snapshots refer to the target by it
+/// since entities differ between worlds
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component, Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct NetworkTargetId(pub u64);
+
+/// Quantized orbit state of a camera for replication, encoded in [`CameraSnapshot::ENCODED_LEN`] bytes.
+/// Angles have a precision of about 0.0055 degrees, the distance is stored in centimeters up to 655.35.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraSnapshot {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub roll: f32,
+    /// Distance between the camera and the target point
+    pub distance: f32,
+    /// `NetworkTargetId` of the target
+    pub target: u64,
+}
+
+fn quantize(value: f32, min: f32, max: f32) -> u16 {
+    ((value - min) / (max - min) * u16::MAX as f32)
+        .round()
+        .clamp(0.0, u16::MAX as f32) as u16
+}
+
+fn dequantize(value: u16, min: f32, max: f32) -> f32 {
+    min + value as f32 / u16::MAX as f32 * (max - min)
+}
+
+impl CameraSnapshot {
+    pub const ENCODED_LEN: usize = 16;
+
+    pub fn from_camera(
+        camera_transform: &Transform,
+        camera_offset: &components::CameraOffset,
+        target: NetworkTargetId,
+    ) -> Self {
+        let (yaw, pitch, roll) = camera_transform.rotation.to_euler(EulerRot::YXZ);
+        Self {
+            yaw,
+            pitch,
+            roll,
+            distance: camera_offset.0.length(),
+            target: target.0,
+        }
+    }
+
+    pub fn encode(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut bytes = [0; Self::ENCODED_LEN];
+        bytes[0..2].copy_from_slice(&quantize(self.yaw, -PI, PI).to_le_bytes());
+        bytes[2..4].copy_from_slice(&quantize(self.pitch, -FRAC_PI_2, FRAC_PI_2).to_le_bytes());
+        bytes[4..6].copy_from_slice(&quantize(self.roll, -PI, PI).to_le_bytes());
+        bytes[6..8].copy_from_slice(&quantize(self.distance, 0.0, 655.35).to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.target.to_le_bytes());
+        bytes
+    }
+
+    /// None if the length is wrong
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; Self::ENCODED_LEN] = bytes.try_into().ok()?;
+        let read_u16 = |at: usize| u16::from_le_bytes([bytes[at], bytes[at + 1]]);
+        Some(Self {
+            yaw: dequantize(read_u16(0), -PI, PI),
+            pitch: dequantize(read_u16(2), -FRAC_PI_2, FRAC_PI_2),
+            roll: dequantize(read_u16(4), -PI, PI),
+            distance: dequantize(read_u16(6), 0.0, 655.35),
+            target: u64::from_le_bytes(bytes[8..16].try_into().ok()?),
+        })
+    }
+
+    /// The target is switched at the end
+    pub fn lerp(&self, rhs: &Self, s: f32) -> Self {
+        Self {
+            yaw: lerp_angle(self.yaw, rhs.yaw, s),
+            pitch: self.pitch.lerp(rhs.pitch, s),
+            roll: lerp_angle(self.roll, rhs.roll, s),
+            distance: self.distance.lerp(rhs.distance, s),
+            target: if s < 1.0 { self.target } else { rhs.target },
+        }
+    }
+}
+
+/// Received snapshots applied to a remote `ThirdPersonCamera`.
+/// The camera is rendered `delay` seconds behind the sender to interpolate between snapshots,
+/// it's aimed at the target with the `NetworkTargetId` of the snapshot when there is one.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct SnapshotBuffer {
+    /// Snapshots with the sender time in seconds, sorted by time
+    pub snapshots: VecDeque<(f32, CameraSnapshot)>,
+    pub delay: f32,
+    /// Sender time estimated on the receiver, starts at the first snapshot
+    pub clock: Option<f32>,
+}
+
+impl Default for SnapshotBuffer {
+    fn default() -> Self {
+        Self {
+            snapshots: VecDeque::new(),
+            delay: 0.1,
+            clock: None,
+        }
+    }
+}
+
+impl SnapshotBuffer {
+    /// Snapshots older than the latest one are dropped
+    pub fn push(&mut self, sent_at: f32, snapshot: CameraSnapshot) {
+        if self
+            .snapshots
+            .back()
+            .is_some_and(|(latest, _)| *latest >= sent_at)
+        {
+            return;
+        }
+        self.clock.get_or_insert(sent_at);
+        self.snapshots.push_back((sent_at, snapshot));
+    }
+
+    /// Snapshot at `time`, holds the first and the last ones outside of the buffer.
+    /// Snapshots no longer needed after `time` are removed.
+    pub fn sample(&mut self, time: f32) -> Option<CameraSnapshot> {
+        while self.snapshots.len() > 1 && self.snapshots[1].0 <= time {
+            self.snapshots.pop_front();
+        }
+        match (self.snapshots.front(), self.snapshots.get(1)) {
+            (Some((from_time, from)), Some((to_time, to))) if time > *from_time => {
+                Some(from.lerp(to, (time - from_time) / (to_time - from_time)))
+            }
+            (Some((_, from)), _) => Some(*from),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,36 @@
 use bevy::prelude::*;
 
-use crate::{ThirdPersonCameraSettings, components, events};
+use crate::{
+    ThirdPersonCameraSettings, components,
+    confiner::CameraConfiner,
+    cycling::{CycleOrder, SpectatorTarget, TargetCycling},
+    events,
+    follow_2d::Follow2d,
+    free_fly::{AttachBlend, FreeFly},
+    ground::GroundClearance,
+    mode::CameraMode,
+    orbit_override::OrbitOverride,
+    rail::CameraRail,
+    recording::{InputRecorder, InputReplay, RecordedInput},
+    sequence::{OrbitState, SequencePlayer},
+    speed::SpeedEffects,
+    target_loss::TargetLossPolicy,
+    volumes::VolumeBlend,
+};
 
+#[allow(clippy::type_complexity)]
 pub fn rotate_camera_o(
     rotate_ev: On<events::RotateAroundTarget>,
     mut commands: Commands,
     camera_settings: Res<ThirdPersonCameraSettings>,
-    mut camera_transform_q: Query<&mut Transform, With<components::ThirdPersonCamera>>,
+    mut camera_transform_q: Query<
+        &mut Transform,
+        (
+            With<components::ThirdPersonCamera>,
+            Without<SequencePlayer>,
+            Without<Follow2d>,
+        ),
+    >,
 ) {
     let Ok(mut camera_transform) = camera_transform_q.get_mut(rotate_ev.camera) else {
         return;
@@ -23,6 +47,11 @@ pub fn rotate_camera_o(
     commands.trigger(events::AdjustTranslation {
         camera: rotate_ev.camera,
     });
+    commands.trigger(events::CameraOrbited {
+        camera: rotate_ev.camera,
+        yaw,
+        pitch,
+    });
 }
 
 pub fn adjust_translation_o(
@@ -35,11 +64,24 @@ pub fn adjust_translation_o(
         ),
         With<components::ThirdPersonCamera>,
     >,
+    free_fly_q: Query<(), With<FreeFly>>,
+    follow_2d_q: Query<(&Follow2d, Option<&Projection>)>,
 ) {
+    if free_fly_q.contains(adjust_translation_ev.camera) {
+        return;
+    }
     if let Ok((mut camera_transform, camera_offset, target_point)) =
         camera_transform_q.get_mut(adjust_translation_ev.camera)
     {
-        camera_transform.translation = target_point.0 - camera_transform.rotation * camera_offset.0;
+        let translation = match follow_2d_q.get(adjust_translation_ev.camera) {
+            Ok((follow_2d, projection_op)) => follow_2d.translation(
+                target_point.0,
+                camera_transform.translation.z,
+                projection_op,
+            ),
+            Err(_) => target_point.0 - camera_transform.rotation * camera_offset.0,
+        };
+        camera_transform.translation = translation;
     } else {
         error!(
             "{} query failed {:?}",
@@ -48,12 +90,57 @@ pub fn adjust_translation_o(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn zoom_o(
     zoom_ev: On<events::Zoom>,
+    mut commands: Commands,
+    camera_settings: Res<ThirdPersonCameraSettings>,
     mut third_person_camera_q: Query<&mut components::CameraOffset>,
+    locked_q: Query<(), Or<(With<SequencePlayer>, With<FreeFly>)>>,
+    mut follow_2d_q: Query<(&Follow2d, &mut Projection)>,
 ) {
+    if locked_q.contains(zoom_ev.camera) {
+        return;
+    }
+    if let Ok((follow_2d, mut projection)) = follow_2d_q.get_mut(zoom_ev.camera) {
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            let scale = follow_2d.zoomed_scale(orthographic.scale, zoom_ev.value);
+            // The area is only refreshed in PostUpdate, the bounds need the new view size now
+            let ratio = scale / orthographic.scale;
+            orthographic.area =
+                Rect::from_corners(orthographic.area.min * ratio, orthographic.area.max * ratio);
+            orthographic.scale = scale;
+            commands.trigger(events::AdjustTranslation {
+                camera: zoom_ev.camera,
+            });
+            commands.trigger(events::CameraScaled {
+                camera: zoom_ev.camera,
+                scale,
+            });
+        }
+        return;
+    }
     if let Ok(mut cam_offset) = third_person_camera_q.get_mut(zoom_ev.camera) {
-        cam_offset.0.z += zoom_ev.value
+        cam_offset.0.z += zoom_ev.value;
+        if let Some((zoom_min, zoom_max)) = camera_settings.zoom_limits {
+            // Only Z is zoomed, the camera is behind the target point on the negative Z
+            let distance = if cam_offset.0.z < 0.0 {
+                cam_offset.0.length()
+            } else {
+                // Zoomed through the target point
+                0.0
+            }
+            .clamp(zoom_min, zoom_max);
+            let side = cam_offset.0.truncate().length_squared();
+            cam_offset.0.z = -(distance * distance - side).max(0.0).sqrt();
+        }
+        commands.trigger(events::AdjustTranslation {
+            camera: zoom_ev.camera,
+        });
+        commands.trigger(events::CameraZoomed {
+            camera: zoom_ev.camera,
+            distance: cam_offset.0.length(),
+        });
     } else {
         error!(
             "{} query failed {:?}",
@@ -64,11 +151,21 @@ pub fn zoom_o(
 
 pub fn roll_camera_o(
     roll_event: On<events::Roll>,
-    mut tp_cam_transform: Query<&mut Transform, With<components::ThirdPersonCamera>>,
+    mut commands: Commands,
+    mut tp_cam_transform: Query<
+        &mut Transform,
+        (With<components::ThirdPersonCamera>, Without<SequencePlayer>),
+    >,
 ) {
     if let Ok(mut cam_transform) = tp_cam_transform.get_mut(roll_event.camera) {
         debug!("roll_event: {:?}", roll_event);
         cam_transform.rotate_local_z(roll_event.value);
+
+        let (_, _, roll) = cam_transform.rotation.to_euler(EulerRot::YXZ);
+        commands.trigger(events::CameraRolled {
+            camera: roll_event.camera,
+            roll,
+        });
     }
 }
 
@@ -78,3 +175,329 @@ pub fn set_local_cam_o(
 ) {
     camera_settings.local_cam = Some(set_local_cam_ev.0)
 }
+
+pub fn target_changed_o(
+    insert_ev: On<Insert, components::ThirdPersonCamera>,
+    mut commands: Commands,
+    tp_cam_q: Query<&components::ThirdPersonCamera>,
+) {
+    if let Ok(tp_cam) = tp_cam_q.get(insert_ev.entity) {
+        commands.trigger(events::TargetChanged {
+            camera: insert_ev.entity,
+            target: tp_cam.target,
+        });
+    }
+}
+
+/// The relationship is removed from the camera after its target is despawned
+pub fn camera_detached_o(
+    remove_ev: On<Remove, components::ThirdPersonCamera>,
+    mut commands: Commands,
+    tp_cam_q: Query<&components::ThirdPersonCamera>,
+    entity_q: Query<()>,
+) {
+    let Ok(tp_cam) = tp_cam_q.get(remove_ev.entity) else {
+        return;
+    };
+    if !entity_q.contains(tp_cam.target) {
+        commands.trigger(events::TargetLost {
+            camera: remove_ev.entity,
+            target: tp_cam.target,
+        });
+    }
+}
+
+pub fn target_lost_o(
+    target_lost_ev: On<events::TargetLost>,
+    mut commands: Commands,
+    camera_settings: Res<ThirdPersonCameraSettings>,
+    camera_policy_q: Query<Option<&TargetLossPolicy>>,
+    entity_q: Query<()>,
+) {
+    let Ok(policy_op) = camera_policy_q.get(target_lost_ev.camera) else {
+        return;
+    };
+    debug!("target_lost_ev: {:?}", target_lost_ev);
+
+    match policy_op
+        .copied()
+        .unwrap_or(camera_settings.default_target_loss_policy)
+    {
+        TargetLossPolicy::Hold => {}
+        TargetLossPolicy::Fallback(fallback) => {
+            if fallback != target_lost_ev.target && entity_q.contains(fallback) {
+                commands
+                    .entity(target_lost_ev.camera)
+                    .try_insert(components::ThirdPersonCamera::aimed_at(fallback));
+            } else {
+                warn!(
+                    "{} fallback target {} doesn't exist",
+                    target_lost_ev.camera, fallback
+                );
+            }
+        }
+    }
+}
+
+/// Gives the orbit pose and field of view back at once.
+/// `AttachBlend` isn't watched, it's removed at the end of the blend, in the middle of the overrides.
+pub fn orbit_override_removed_o(
+    remove_ev: On<
+        Remove,
+        (
+            SpeedEffects,
+            CameraConfiner,
+            CameraRail,
+            GroundClearance,
+            VolumeBlend,
+            CameraMode,
+        ),
+    >,
+    mut override_q: Query<(&mut Transform, &mut OrbitOverride, Option<&mut Projection>)>,
+) {
+    if let Ok((camera_transform, mut orbit_override, projection_op)) =
+        override_q.get_mut(remove_ev.entity)
+    {
+        orbit_override.restore(camera_transform, projection_op);
+    }
+}
+
+pub fn detach_camera_o(detach_ev: On<events::DetachCamera>, mut commands: Commands) {
+    commands
+        .entity(detach_ev.camera)
+        .remove::<AttachBlend>()
+        .entry::<FreeFly>()
+        .or_default();
+}
+
+pub fn attach_camera_o(
+    attach_ev: On<events::AttachCamera>,
+    mut commands: Commands,
+    camera_q: Query<(&Transform, Option<&components::ThirdPersonCamera>)>,
+) {
+    let Ok((camera_transform, tp_cam_op)) = camera_q.get(attach_ev.camera) else {
+        error!("{} query failed {:?}", attach_ev.camera, camera_q);
+        return;
+    };
+
+    let mut camera_commands = commands.entity(attach_ev.camera);
+    // Without the relationship when the target was despawned
+    if tp_cam_op.is_none_or(|tp_cam| tp_cam.target != attach_ev.target) {
+        camera_commands.insert(components::ThirdPersonCamera::aimed_at(attach_ev.target));
+    }
+    camera_commands.remove::<FreeFly>().insert(AttachBlend::new(
+        *camera_transform,
+        attach_ev.blend_duration,
+    ));
+    // The target point may not change, the orbit pose is placed anyway
+    commands.trigger(events::AdjustTranslation {
+        camera: attach_ev.camera,
+    });
+}
+
+pub fn cycle_target_o(
+    cycle_ev: On<events::CycleTarget>,
+    mut commands: Commands,
+    camera_q: Query<(
+        &GlobalTransform,
+        Option<&components::ThirdPersonCamera>,
+        Option<&TargetCycling>,
+    )>,
+    target_q: Query<(EntityRef, &GlobalTransform, &SpectatorTarget)>,
+) {
+    let Ok((camera_transform, tp_cam_op, cycling_op)) = camera_q.get(cycle_ev.camera) else {
+        error!("{} query failed {:?}", cycle_ev.camera, camera_q);
+        return;
+    };
+    let mut cycling = cycling_op.copied().unwrap_or_default();
+    // Measured from the same point while cycling, the camera moves to each target
+    let origin = *cycling.origin.get_or_insert(camera_transform.translation());
+    if cycling_op.is_none_or(|cycling| cycling.origin.is_none()) {
+        commands.entity(cycle_ev.camera).insert(cycling);
+    }
+
+    // Despawned targets are no longer in the query
+    let mut targets: Vec<_> = target_q
+        .iter()
+        .filter(|(target, _, _)| cycling.filter.is_none_or(|filter| filter(*target)))
+        .map(|(target, target_transform, spectator_target)| {
+            let distance = target_transform.translation().distance_squared(origin);
+            (target.id(), distance, spectator_target.priority)
+        })
+        .collect();
+    match cycling.order {
+        CycleOrder::Distance => targets.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0))),
+        CycleOrder::Priority => targets.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0))),
+    }
+
+    // Without the relationship when the target was despawned
+    let current = tp_cam_op.and_then(|tp_cam| {
+        targets
+            .iter()
+            .position(|(target, _, _)| *target == tp_cam.target)
+    });
+    let len = targets.len();
+    let index = match (cycle_ev.direction, current) {
+        (_, Some(_)) if len < 2 => return,
+        (events::CycleDirection::Next, Some(index)) => (index + 1) % len,
+        (events::CycleDirection::Previous, Some(index)) => (index + len - 1) % len,
+        (_, None) if len == 0 => return,
+        (events::CycleDirection::Next, None) => 0,
+        (events::CycleDirection::Previous, None) => len - 1,
+    };
+
+    commands.trigger(events::AttachCamera {
+        camera: cycle_ev.camera,
+        target: targets[index].0,
+        blend_duration: cycling.blend_duration,
+    });
+}
+
+pub fn set_camera_mode_o(
+    mode_ev: On<events::SetCameraMode>,
+    mut commands: Commands,
+    // Also without the relationship when the target was despawned, the target point is kept
+    camera_q: Query<(&Transform, Option<&CameraMode>), With<components::TargetPoint>>,
+) {
+    let Ok((camera_transform, mode_op)) = camera_q.get(mode_ev.camera) else {
+        error!("{} query failed {:?}", mode_ev.camera, camera_q);
+        return;
+    };
+    if mode_op.copied().unwrap_or_default() == mode_ev.mode {
+        return;
+    }
+
+    let mut camera_commands = commands.entity(mode_ev.camera);
+    camera_commands.insert(mode_ev.mode);
+    if mode_ev.transition > 0.0 {
+        camera_commands.insert(AttachBlend::new(*camera_transform, mode_ev.transition));
+    }
+    commands.trigger(events::CameraModeChanged {
+        camera: mode_ev.camera,
+        mode: mode_ev.mode,
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub fn play_sequence_o(
+    play_ev: On<events::PlaySequence>,
+    mut commands: Commands,
+    camera_q: Query<(
+        &Transform,
+        &components::CameraOffset,
+        &components::TargetOffset,
+        Option<&Projection>,
+        Option<&SequencePlayer>,
+    )>,
+) {
+    let Ok((camera_transform, camera_offset, target_offset, projection_op, player_op)) =
+        camera_q.get(play_ev.camera)
+    else {
+        error!("{} query failed {:?}", play_ev.camera, camera_q);
+        return;
+    };
+
+    // A sequence started over another one returns to the player's state as well
+    let (start, start_camera_offset) = player_op.map_or_else(
+        || {
+            (
+                OrbitState::from_camera(
+                    camera_transform,
+                    camera_offset,
+                    target_offset,
+                    projection_op,
+                ),
+                camera_offset.0,
+            )
+        },
+        |player| (player.start, player.start_camera_offset),
+    );
+
+    commands.entity(play_ev.camera).insert(SequencePlayer {
+        sequence: play_ev.sequence.clone(),
+        elapsed: 0.0,
+        paused: false,
+        start,
+        start_camera_offset,
+    });
+}
+
+pub fn pause_sequence_o(
+    pause_ev: On<events::PauseSequence>,
+    mut player_q: Query<&mut SequencePlayer>,
+) {
+    if let Ok(mut player) = player_q.get_mut(pause_ev.camera) {
+        player.paused = pause_ev.paused;
+    }
+}
+
+pub fn skip_sequence_o(
+    skip_ev: On<events::SkipSequence>,
+    mut player_q: Query<&mut SequencePlayer>,
+) {
+    if let Ok(mut player) = player_q.get_mut(skip_ev.camera) {
+        // Finished by play_sequence_s on the next update
+        player.elapsed = f32::INFINITY;
+        player.paused = false;
+    }
+}
+
+pub fn recording_started_o(
+    add_ev: On<Add, InputRecorder>,
+    time: Res<Time>,
+    mut recorder_q: Query<&mut InputRecorder>,
+) {
+    if let Ok(mut recorder) = recorder_q.get_mut(add_ev.entity) {
+        recorder.started = time.elapsed();
+    }
+}
+
+pub fn replay_started_o(
+    add_ev: On<Add, InputReplay>,
+    time: Res<Time>,
+    mut replay_q: Query<&mut InputReplay>,
+) {
+    if let Ok(mut replay) = replay_q.get_mut(add_ev.entity) {
+        replay.started = time.elapsed();
+    }
+}
+
+pub fn record_rotate_o(
+    rotate_ev: On<events::RotateAroundTarget>,
+    time: Res<Time>,
+    mut recorder_q: Query<&mut InputRecorder>,
+) {
+    if let Ok(mut recorder) = recorder_q.get_mut(rotate_ev.camera) {
+        recorder.record(time.elapsed(), RecordedInput::Rotate(rotate_ev.delta));
+    }
+}
+
+pub fn record_zoom_o(
+    zoom_ev: On<events::Zoom>,
+    time: Res<Time>,
+    mut recorder_q: Query<&mut InputRecorder>,
+) {
+    if let Ok(mut recorder) = recorder_q.get_mut(zoom_ev.camera) {
+        recorder.record(time.elapsed(), RecordedInput::Zoom(zoom_ev.value));
+    }
+}
+
+pub fn record_roll_o(
+    roll_ev: On<events::Roll>,
+    time: Res<Time>,
+    mut recorder_q: Query<&mut InputRecorder>,
+) {
+    if let Ok(mut recorder) = recorder_q.get_mut(roll_ev.camera) {
+        recorder.record(time.elapsed(), RecordedInput::Roll(roll_ev.value));
+    }
+}
+
+pub fn record_set_local_cam_o(
+    set_local_cam_ev: On<events::SetLocalCamera>,
+    time: Res<Time>,
+    mut recorder_q: Query<&mut InputRecorder>,
+) {
+    if let Ok(mut recorder) = recorder_q.get_mut(set_local_cam_ev.0) {
+        recorder.record(time.elapsed(), RecordedInput::SetLocalCamera);
+    }
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Shape in the local space of the `Occluder`
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum OccluderShape {
+    Aabb { half_size: Vec3 },
+    Sphere { radius: f32 },
+}
+
+/// Geometry faded out while it's between a camera and its target point
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct Occluder(pub OccluderShape);
+
+/// Inserted on the occluders hiding a target, removed once they are faded back in.
+/// `alpha` drives the transparency of the entity, see `OccluderFade`.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct OccludingTarget {
+    pub alpha: f32,
+    /// False while fading back in
+    pub occluding: bool,
+}
+
+/// Occluders between the camera and its target point on the last frame, for `CameraCollided`
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub(crate) struct CameraOccluders(pub(crate) Vec<Entity>);
+
+/// How the occluders are faded
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct OccluderFade {
+    /// Alpha of the occluders hiding a target
+    pub alpha: f32,
+    /// Alpha change per second
+    pub speed: f32,
+    /// Called when the alpha of an occluder changes, for example to update its material
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub on_fade: Option<fn(&mut EntityCommands, f32)>,
+}
+
+impl Default for OccluderFade {
+    fn default() -> Self {
+        Self {
+            alpha: 0.3,
+            speed: 4.0,
+            on_fade: None,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+/// Orbit pose under the overrides of the final pose.
+/// Stashed by the first of them to change the Transform in a frame and restored before
+/// the controls run, so the orbit controls never see an overridden pose.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub(crate) struct OrbitOverride {
+    pub(crate) transform: Option<Transform>,
+    /// Perspective field of view under the speed effects
+    pub(crate) fov: Option<f32>,
+}
+
+impl OrbitOverride {
+    /// Call before changing the Transform, only the first pose of the frame is kept
+    pub(crate) fn stash(&mut self, camera_transform: &Transform) {
+        self.transform.get_or_insert(*camera_transform);
+    }
+
+    /// Call before changing the field of view
+    pub(crate) fn stash_fov(&mut self, fov: f32) {
+        self.fov.get_or_insert(fov);
+    }
+
+    pub(crate) fn restore(
+        &mut self,
+        mut camera_transform: Mut<Transform>,
+        projection_op: Option<Mut<Projection>>,
+    ) {
+        if let Some(orbit_transform) = self.transform.take() {
+            *camera_transform = orbit_transform;
+        }
+        if let Some(fov) = self.fov.take()
+            && let Some(mut projection) = projection_op
+            && let Projection::Perspective(perspective) = projection.as_mut()
+        {
+            perspective.fov = fov;
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use bevy::prelude::*;
 
-#[derive(Resource, Debug, Clone, Copy)]
+use crate::target_loss::TargetLossPolicy;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
 pub struct ThirdPersonCameraSettings {
     /// The position of the camera relative to the target point.
     /// Can be changed for a specific camera using the СameraOffset component.
@@ -15,13 +24,25 @@ pub struct ThirdPersonCameraSettings {
     /// Smooth target following
     pub default_damping: Option<f32>,
 
+    /// What the camera does when its target is despawned.
+    /// Can be changed for a specific camera using the TargetLossPolicy component.
+    pub default_target_loss_policy: TargetLossPolicy,
+
     pub pitch_max: f32,
     pub pitch_min: f32,
 
-    /// Draw line between target and cameras
+    /// Draw debug gizmos of the cameras, see `CameraGizmosConfig` for what is drawn
     pub show_relation_gizmo: bool,
 
-    /// Camera that controlled
+    /// Minimum and maximum distance between the camera and the target point.
+    /// The zoom is not limited if None.
+    pub zoom_limits: Option<(f32, f32)>,
+
+    /// Distance from the target point of the extra rays for `TargetVisibility`
+    pub visibility_sample_radius: f32,
+
+    /// Camera that controlled, an entity id means nothing in a settings file
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub local_cam: Option<Entity>,
 
     pub cam_speed: f32,
@@ -47,11 +68,17 @@ impl Default for ThirdPersonCameraSettings {
 
             default_damping: None,
 
+            default_target_loss_policy: TargetLossPolicy::Hold,
+
             pitch_max: 89f32.to_radians(),
             pitch_min: -89f32.to_radians(),
 
             show_relation_gizmo: false,
 
+            zoom_limits: None,
+
+            visibility_sample_radius: 0.5,
+
             local_cam: None,
 
             up: KeyCode::ArrowUp,
@@ -64,3 +91,63 @@ impl Default for ThirdPersonCameraSettings {
         }
     }
 }
+
+/// Debug gizmos drawn while `ThirdPersonCameraSettings::show_relation_gizmo` is set
+#[cfg(feature = "draw_relation_lines")]
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Debug, Default, Clone)]
+pub struct CameraGizmosConfig {
+    /// Line between the target point and the camera
+    pub relation_line: bool,
+    /// Sphere of the camera positions at the current distance
+    pub orbit_sphere: bool,
+    /// Cones of the camera positions at `pitch_min` and `pitch_max`
+    pub pitch_limits: bool,
+    /// Target point without damping (cross) and the damped `TargetPoint` (sphere)
+    pub target_points: bool,
+    /// Line between the target and the target point
+    pub target_offset: bool,
+    /// Segment between the `zoom_limits` distances toward the camera
+    pub zoom_range: bool,
+}
+
+#[cfg(feature = "draw_relation_lines")]
+impl Default for CameraGizmosConfig {
+    fn default() -> Self {
+        Self {
+            relation_line: true,
+            orbit_sphere: false,
+            pitch_limits: false,
+            target_points: false,
+            target_offset: false,
+            zoom_range: false,
+        }
+    }
+}
+
+/// Text with the camera state drawn in front of every camera
+#[cfg(feature = "debug_overlay")]
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Debug, Default, Clone)]
+pub struct DebugOverlayConfig {
+    pub visible: bool,
+    /// Key to show or hide the overlay
+    pub toggle: KeyCode,
+    /// Top left corner of the text in the camera space
+    pub offset: Vec3,
+    pub font_size: f32,
+    pub color: Color,
+}
+
+#[cfg(feature = "debug_overlay")]
+impl Default for DebugOverlayConfig {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle: KeyCode::F3,
+            offset: Vec3::new(-0.35, 0.3, -1.0),
+            font_size: 0.015,
+            color: Color::WHITE,
+        }
+    }
+}
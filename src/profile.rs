@@ -0,0 +1,126 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use ron::extensions::Extensions;
+use serde::Deserialize;
+
+use crate::{ThirdPersonCameraSettings, components};
+
+/// Camera preset loaded from a `*.tpcam.ron` file.
+/// Every field is optional, only the specified values override the current ones.
+///
+/// ```ron
+/// (
+///     camera_offset: (0.0, 2.0, -10.0),
+///     damping: 5.0,
+///     pitch_max: 1.2,
+///     roll_clockwise: KeyR,
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThirdPersonCameraProfile {
+    pub camera_offset: Option<Vec3>,
+    pub target_offset: Option<Vec3>,
+    pub damping: Option<f32>,
+
+    pub pitch_max: Option<f32>,
+    pub pitch_min: Option<f32>,
+
+    pub cam_speed: Option<f32>,
+    pub mouse_speed: Option<f32>,
+
+    pub up: Option<KeyCode>,
+    pub down: Option<KeyCode>,
+    pub left: Option<KeyCode>,
+    pub right: Option<KeyCode>,
+
+    pub roll_clockwise: Option<KeyCode>,
+    pub roll_counterclockwise: Option<KeyCode>,
+}
+
+impl ThirdPersonCameraProfile {
+    /// Override the plugin settings with the values from the profile
+    pub fn apply_to_settings(&self, settings: &mut ThirdPersonCameraSettings) {
+        fn set<T: Copy>(field: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        set(&mut settings.default_camera_offset, self.camera_offset);
+        set(&mut settings.default_target_offset, self.target_offset);
+        if self.damping.is_some() {
+            settings.default_damping = self.damping;
+        }
+
+        set(&mut settings.pitch_max, self.pitch_max);
+        set(&mut settings.pitch_min, self.pitch_min);
+
+        set(&mut settings.cam_speed, self.cam_speed);
+        set(&mut settings.mouse_speed, self.mouse_speed);
+
+        set(&mut settings.up, self.up);
+        set(&mut settings.down, self.down);
+        set(&mut settings.left, self.left);
+        set(&mut settings.right, self.right);
+
+        set(&mut settings.roll_clockwise, self.roll_clockwise);
+        set(
+            &mut settings.roll_counterclockwise,
+            self.roll_counterclockwise,
+        );
+    }
+
+    /// Override the components of a specific camera with the values from the profile
+    pub fn apply_to_camera(&self, camera: &mut EntityCommands) {
+        if let Some(camera_offset) = self.camera_offset {
+            camera.insert(components::CameraOffset(camera_offset));
+        }
+        if let Some(target_offset) = self.target_offset {
+            camera.insert(components::TargetOffset(target_offset));
+        }
+        if let Some(damping) = self.damping {
+            camera.insert(components::DampingFactor(damping));
+        }
+    }
+}
+
+/// Profile applied to the [`ThirdPersonCameraSettings`], reapplied on every change of the file
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource, Debug, Clone)]
+pub struct SettingsProfile(pub Handle<ThirdPersonCameraProfile>);
+
+/// Profile applied to the components of this camera, reapplied on every change of the file
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+pub struct CameraProfile(pub Handle<ThirdPersonCameraProfile>);
+
+#[derive(TypePath, Default)]
+pub struct ThirdPersonCameraProfileLoader;
+
+impl AssetLoader for ThirdPersonCameraProfileLoader {
+    type Asset = ThirdPersonCameraProfile;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let profile = ron::Options::default()
+            .with_default_extension(Extensions::IMPLICIT_SOME)
+            .from_bytes(&bytes)?;
+        Ok(profile)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tpcam.ron"]
+    }
+}
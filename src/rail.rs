@@ -0,0 +1,62 @@
+use bevy::{math::cubic_splines::CubicCurve, prelude::*};
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// How the position on the [`CameraRail`] is chosen
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum RailParameterization {
+    /// Travel along the curve, `speed` in curve segments per second
+    Time { speed: f32, looping: bool },
+    /// Stay at the point of the curve closest to the target point
+    NearestToTarget,
+}
+
+/// Moves the camera along a curve, for example
+/// `CubicCardinalSpline::new_catmull_rom(points).to_curve()`,
+/// while the camera keeps looking at the target point.
+/// The orbit controls keep working and take over when the rail is blended out.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[require(OrbitOverride)]
+pub struct CameraRail {
+    pub curve: CubicCurve<Vec3>,
+    pub parameterization: RailParameterization,
+    /// Current position on the curve, from 0 to the number of curve segments
+    pub t: f32,
+    /// Influence of the rail: 0 is the free orbit, 1 is the camera on the rail
+    pub weight: f32,
+    /// The weight moves to this value by `blend_speed` per second
+    pub target_weight: f32,
+    pub blend_speed: f32,
+}
+
+impl CameraRail {
+    /// The rail blends in during one second
+    pub fn new(curve: CubicCurve<Vec3>, parameterization: RailParameterization) -> Self {
+        Self {
+            curve,
+            parameterization,
+            t: 0.0,
+            weight: 0.0,
+            target_weight: 1.0,
+            blend_speed: 1.0,
+        }
+    }
+
+    pub fn blend_in(&mut self) {
+        self.target_weight = 1.0;
+    }
+
+    /// Return the camera to the free orbit
+    pub fn blend_out(&mut self) {
+        self.target_weight = 0.0;
+    }
+}
@@ -0,0 +1,112 @@
+use core::time::Duration;
+
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Camera control event stored in a [`CameraInputLog`]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum RecordedInput {
+    /// `RotateAroundTarget` delta
+    Rotate(Vec2),
+    Zoom(f32),
+    Roll(f32),
+    /// The camera was made the local camera, only logged on replay
+    SetLocalCamera,
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct RecordedEvent {
+    /// Seconds from the start of the recording
+    pub time: f32,
+    pub input: RecordedInput,
+}
+
+/// Control events of a camera in the order they were triggered.
+/// Stored as RON with the `serialize` feature:
+///
+/// ```ron
+/// (
+///     events: [
+///         (time: 0.016, input: Rotate((12.0, -3.0))),
+///         (time: 0.032, input: Zoom(1.0)),
+///     ],
+/// )
+/// ```
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraInputLog {
+    pub events: Vec<RecordedEvent>,
+}
+
+#[cfg(feature = "serialize")]
+impl CameraInputLog {
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), BevyError> {
+        std::fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, BevyError> {
+        Ok(Self::from_ron(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+/// Records the control events of this camera from the moment it's inserted
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Debug, Clone, Default)]
+pub struct InputRecorder {
+    pub log: CameraInputLog,
+    #[reflect(ignore)]
+    pub(crate) started: Duration,
+}
+
+impl InputRecorder {
+    pub(crate) fn record(&mut self, now: Duration, input: RecordedInput) {
+        self.log.events.push(RecordedEvent {
+            time: now.saturating_sub(self.started).as_secs_f32(),
+            input,
+        });
+    }
+}
+
+/// Triggers the events of the log on this camera, timed from the moment it's inserted.
+/// With the same target motion the camera goes through the same poses as when recorded,
+/// the keyboard and mouse are ignored meanwhile if it's the local camera.
+/// Removed when the log is over, see `ReplayFinished`.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+pub struct InputReplay {
+    pub log: CameraInputLog,
+    #[reflect(ignore)]
+    pub(crate) started: Duration,
+    /// Index of the next event to trigger
+    #[reflect(ignore)]
+    pub(crate) next: usize,
+}
+
+impl InputReplay {
+    pub fn new(log: CameraInputLog) -> Self {
+        Self {
+            log,
+            started: Duration::ZERO,
+            next: 0,
+        }
+    }
+}
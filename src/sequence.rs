@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::{components, math::lerp_angle};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Orbit parameters of the camera, in radians and world units
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct OrbitState {
+    pub yaw: f32,
+    pub pitch: f32,
+    /// Distance between the camera and the target point
+    pub distance: f32,
+    pub target_offset: Vec3,
+    /// Vertical field of view of a perspective camera, None keeps the current one
+    pub fov: Option<f32>,
+}
+
+impl OrbitState {
+    pub fn from_camera(
+        camera_transform: &Transform,
+        camera_offset: &components::CameraOffset,
+        target_offset: &components::TargetOffset,
+        projection_op: Option<&Projection>,
+    ) -> Self {
+        let (yaw, pitch, _) = camera_transform.rotation.to_euler(EulerRot::YXZ);
+        Self {
+            yaw,
+            pitch,
+            distance: camera_offset.0.length(),
+            target_offset: target_offset.0,
+            fov: match projection_op {
+                Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+                _ => None,
+            },
+        }
+    }
+
+    /// Set the camera to this state, the roll and the direction of the camera offset are kept.
+    /// Translation is adjusted by the `AdjustTranslation` event.
+    pub fn apply(
+        &self,
+        camera_transform: &mut Transform,
+        camera_offset: &mut components::CameraOffset,
+        target_offset: &mut components::TargetOffset,
+        projection_op: Option<&mut Projection>,
+    ) {
+        let (_, _, roll) = camera_transform.rotation.to_euler(EulerRot::YXZ);
+        camera_transform.rotation = Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, roll);
+        camera_offset.0 = camera_offset.0.normalize_or(Vec3::NEG_Z) * self.distance;
+        target_offset.0 = self.target_offset;
+        if let (Some(fov), Some(Projection::Perspective(perspective))) = (self.fov, projection_op) {
+            perspective.fov = fov;
+        }
+    }
+
+    /// The yaw turns along the shortest arc
+    pub fn lerp(&self, rhs: &Self, s: f32) -> Self {
+        Self {
+            yaw: lerp_angle(self.yaw, rhs.yaw, s),
+            pitch: self.pitch.lerp(rhs.pitch, s),
+            distance: self.distance.lerp(rhs.distance, s),
+            target_offset: self.target_offset.lerp(rhs.target_offset, s),
+            fov: match (self.fov, rhs.fov) {
+                (Some(fov), Some(rhs_fov)) => Some(fov.lerp(rhs_fov, s)),
+                (fov, rhs_fov) => rhs_fov.or(fov),
+            },
+        }
+    }
+}
+
+/// Orbit state reached at `time` seconds from the start of the sequence
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraKeyframe {
+    pub time: f32,
+    pub state: OrbitState,
+    /// Easing from the previous keyframe to this one
+    pub easing: EaseFunction,
+}
+
+/// Camera sequence played with the `PlaySequence` event.
+/// Starts from the current orbit state of the camera and returns to it at the end.
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraSequence {
+    /// Sorted by time
+    pub keyframes: Vec<CameraKeyframe>,
+    /// Time to return to the player's orbit state after the last keyframe
+    pub return_duration: f32,
+    pub return_easing: Option<EaseFunction>,
+}
+
+impl CameraSequence {
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time) + self.return_duration
+    }
+
+    /// Orbit state at `time`, `start` is the player's orbit state
+    pub fn sample(&self, start: &OrbitState, time: f32) -> OrbitState {
+        let start_keyframe = CameraKeyframe {
+            time: 0.0,
+            state: *start,
+            easing: EaseFunction::Linear,
+        };
+        let return_keyframe = CameraKeyframe {
+            time: self.duration(),
+            state: *start,
+            easing: self.return_easing.unwrap_or(EaseFunction::Linear),
+        };
+        let keyframes = core::iter::once(&start_keyframe)
+            .chain(&self.keyframes)
+            .chain(core::iter::once(&return_keyframe));
+
+        let mut previous = &start_keyframe;
+        for keyframe in keyframes {
+            if time < keyframe.time {
+                let span = keyframe.time - previous.time;
+                let s = keyframe
+                    .easing
+                    .sample_clamped((time - previous.time) / span);
+                return previous.state.lerp(&keyframe.state, s);
+            }
+            previous = keyframe;
+        }
+        *start
+    }
+}
+
+/// Playback of a `CameraSequence`, the player's orbit controls are ignored while it's present
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+pub struct SequencePlayer {
+    pub sequence: CameraSequence,
+    pub elapsed: f32,
+    pub paused: bool,
+    /// Orbit state of the player before the sequence
+    pub start: OrbitState,
+    /// Camera offset of the player before the sequence, restored exactly at the end
+    pub start_camera_offset: Vec3,
+}
+
+impl SequencePlayer {
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.sequence.duration()
+    }
+}
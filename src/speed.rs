@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+
+use crate::{components::VelocityEstimate, orbit_override::OrbitOverride};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Piecewise linear curve from the target speed to a value, points sorted by speed.
+/// Clamped to the first and last points.
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct SpeedCurve(pub Vec<Vec2>);
+
+impl SpeedCurve {
+    /// Linear from 0 at `start_speed` to `value` at `end_speed`
+    pub fn ramp(start_speed: f32, end_speed: f32, value: f32) -> Self {
+        Self(vec![
+            Vec2::new(start_speed, 0.0),
+            Vec2::new(end_speed, value),
+        ])
+    }
+
+    pub fn sample(&self, speed: f32) -> f32 {
+        let Some(first) = self.0.first() else {
+            return 0.0;
+        };
+        if speed <= first.x {
+            return first.y;
+        }
+        self.0.windows(2).find(|points| speed < points[1].x).map_or(
+            self.0[self.0.len() - 1].y,
+            |points| {
+                let s = (speed - points[0].x) / (points[1].x - points[0].x);
+                points[0].y.lerp(points[1].y, s)
+            },
+        )
+    }
+}
+
+/// Widens the field of view and pulls the camera back as the target goes faster.
+/// The speed is estimated from the target's movement, or read from its `TargetVelocity`.
+/// The modifiers are applied on top of the orbit pose and the perspective projection
+/// each frame, the `CameraOffset` and the base field of view are left untouched.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[require(OrbitOverride)]
+pub struct SpeedEffects {
+    /// Field of view added in radians
+    pub fov: SpeedCurve,
+    /// Distance added between the camera and the target point
+    pub distance: SpeedCurve,
+    /// The higher the value, the faster the modifiers follow speed changes
+    pub smoothing: f32,
+    /// Smoothed speed of the target
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) speed: f32,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub(crate) velocity: VelocityEstimate,
+}
+
+impl Default for SpeedEffects {
+    fn default() -> Self {
+        Self::new(SpeedCurve::default(), SpeedCurve::default())
+    }
+}
+
+impl SpeedEffects {
+    pub fn new(fov: SpeedCurve, distance: SpeedCurve) -> Self {
+        Self {
+            fov,
+            distance,
+            smoothing: 3.0,
+            speed: 0.0,
+            velocity: VelocityEstimate::default(),
+        }
+    }
+
+    /// Smoothed speed of the target
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
@@ -3,27 +3,35 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{components, events, plugin_settings::ThirdPersonCameraSettings};
+use crate::{
+    components, events, follow_2d::Follow2d, orbit_override::OrbitOverride,
+    plugin_settings::ThirdPersonCameraSettings, recording::InputReplay,
+};
+
+/// Give the orbit controls back their own pose
+pub fn restore_orbit_override_s(
+    mut override_q: Query<(&mut Transform, &mut OrbitOverride, Option<&mut Projection>)>,
+) {
+    for (camera_transform, mut orbit_override, projection_op) in &mut override_q {
+        orbit_override.restore(camera_transform, projection_op);
+    }
+}
 
+#[allow(clippy::type_complexity)]
 pub fn spawn_components_s(
     mut commands: Commands,
     tp_cam_settings: Res<ThirdPersonCameraSettings>,
     tp_cam_q: Query<
         (
             Entity,
-            &components::ThirdPersonCamera,
             Has<components::CameraOffset>,
             Has<components::TargetOffset>,
-            Has<components::TargetPoint>,
             Has<components::DampingFactor>,
         ),
         Added<components::ThirdPersonCamera>,
     >,
-    target_transform_q: Query<&Transform, With<components::ThirdPersonCameraTarget>>,
 ) {
-    for (tp_cam_entity, tp_cam, has_cam_offset, has_target_offset, has_target_point, has_damping) in
-        tp_cam_q
-    {
+    for (tp_cam_entity, has_cam_offset, has_target_offset, has_damping) in tp_cam_q {
         if !has_cam_offset {
             commands
                 .entity(tp_cam_entity)
@@ -38,17 +46,6 @@ pub fn spawn_components_s(
                     tp_cam_settings.default_target_offset,
                 ));
         }
-        if !has_target_point {
-            if let Ok(target_transform) = target_transform_q.get(tp_cam.target) {
-                commands
-                    .entity(tp_cam_entity)
-                    .insert(components::TargetPoint(
-                        target_transform.translation + tp_cam_settings.default_target_offset,
-                    ));
-            } else {
-                error!("{} query failed {:?}", tp_cam.target, target_transform_q);
-            }
-        }
         tp_cam_settings.default_damping.inspect(|damping_factor| {
             if !has_damping {
                 commands
@@ -59,46 +56,234 @@ pub fn spawn_components_s(
     }
 }
 
+/// Retried every frame until the target gets a `Transform`.
+/// Places the camera right away, so it never renders a frame in the wrong pose.
+#[allow(clippy::type_complexity)]
+pub fn init_target_point_s(
+    mut commands: Commands,
+    tp_cam_settings: Res<ThirdPersonCameraSettings>,
+    tp_cam_q: Query<
+        (
+            Entity,
+            &components::ThirdPersonCamera,
+            Option<&components::CameraOffset>,
+            Option<&components::TargetOffset>,
+            Option<&components::InitialRotation>,
+            Option<(&Follow2d, Option<&Projection>)>,
+        ),
+        Without<components::TargetPoint>,
+    >,
+    // Both the cameras and the targets, a camera can be the target of another one
+    mut transform_q: Query<&mut Transform>,
+) {
+    for (
+        tp_cam_entity,
+        tp_cam,
+        cam_offset_op,
+        target_offset_op,
+        initial_rotation_op,
+        follow_2d_op,
+    ) in tp_cam_q
+    {
+        let Ok(target_transform) = transform_q.get(tp_cam.target).copied() else {
+            debug!(
+                "{} target {} has no Transform yet",
+                tp_cam_entity, tp_cam.target
+            );
+            continue;
+        };
+        let Ok(mut tp_cam_transform) = transform_q.get_mut(tp_cam_entity) else {
+            continue;
+        };
+        let cam_offset = cam_offset_op
+            .map_or(tp_cam_settings.default_camera_offset, |cam_offset| {
+                cam_offset.0
+            });
+        let target_offset = target_offset_op
+            .map_or(tp_cam_settings.default_target_offset, |target_offset| {
+                target_offset.0
+            });
+        let target_point = target_transform.translation + target_offset;
+
+        tp_cam_transform.translation = match follow_2d_op {
+            Some((follow_2d, projection_op)) => {
+                follow_2d.translation(target_point, tp_cam_transform.translation.z, projection_op)
+            }
+            None => {
+                if let Some(initial_rotation) = initial_rotation_op {
+                    tp_cam_transform.rotation = Quat::from_euler(
+                        EulerRot::YXZ,
+                        initial_rotation.yaw,
+                        initial_rotation
+                            .pitch
+                            .clamp(tp_cam_settings.pitch_min, tp_cam_settings.pitch_max),
+                        0.0,
+                    );
+                }
+                target_point - tp_cam_transform.rotation * cam_offset
+            }
+        };
+
+        commands
+            .entity(tp_cam_entity)
+            .insert(components::TargetPoint(target_point));
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub fn calculate_target_point_s(
     time: Res<Time>,
     target_transform_q: Query<
-        (&GlobalTransform, &components::ThirdPersonCameraTarget),
-        With<components::ThirdPersonCameraTarget>,
-    >,
-    mut camera_transform_q: Query<
         (
-            &components::TargetOffset,
-            &mut components::TargetPoint,
-            Option<&components::DampingFactor>,
+            Ref<GlobalTransform>,
+            &components::ThirdPersonCameraTarget,
+            Option<&components::TargetVelocity>,
         ),
-        Without<components::ThirdPersonCameraTarget>,
+        With<components::ThirdPersonCameraTarget>,
     >,
+    mut camera_transform_q: Query<(
+        &Transform,
+        &components::TargetOffset,
+        &mut components::TargetPoint,
+        Option<&components::DampingFactor>,
+        Option<&components::AxisDamping>,
+        Option<&mut components::LookAhead>,
+        Option<&components::FramingZones>,
+    )>,
 ) {
-    for (target_transform, target) in target_transform_q {
+    for (target_transform, target, velocity_op) in target_transform_q {
+        // Not propagated yet, the target point is already initialized from the Transform
+        if target_transform.is_added() {
+            continue;
+        }
+        let target_translation = target_transform.translation();
         for camera_entity in target.iter() {
-            if let Ok((target_offset, mut target_point, damping_op)) =
-                camera_transform_q.get_mut(camera_entity)
+            if let Ok((
+                camera_transform,
+                target_offset,
+                mut target_point,
+                damping_op,
+                axis_damping_op,
+                look_ahead_op,
+                framing_op,
+            )) = camera_transform_q.get_mut(camera_entity)
             {
-                let absolute = target_transform.translation() + target_offset.0;
+                let look_ahead_offset = look_ahead_op.map_or(Vec3::ZERO, |mut look_ahead| {
+                    update_look_ahead(&mut look_ahead, target_translation, velocity_op, &time)
+                });
+                let absolute = target_translation + target_offset.0 + look_ahead_offset;
 
-                target_point.0 = damping_op.map_or(absolute, |damping_factor| {
-                    target_point
+                let new_target_point = match (framing_op, axis_damping_op, damping_op) {
+                    (Some(framing), _, _) => frame_target_point(
+                        framing,
+                        camera_transform.rotation,
+                        target_point.0,
+                        absolute,
+                        time.delta_secs(),
+                    ),
+                    (None, Some(axis_damping), _) => damp_per_axis(
+                        axis_damping,
+                        target_transform.rotation(),
+                        target_point.0,
+                        absolute,
+                        time.delta_secs(),
+                    ),
+                    (None, None, Some(damping_factor)) => target_point
                         .0
-                        .lerp(absolute, time.delta_secs() * damping_factor.0)
-                })
+                        .lerp(absolute, time.delta_secs() * damping_factor.0),
+                    (None, None, None) => absolute,
+                };
+                target_point.set_if_neq(components::TargetPoint(new_target_point));
             }
         }
     }
 }
 
+fn damp_per_axis(
+    axis_damping: &components::AxisDamping,
+    target_rotation: Quat,
+    target_point: Vec3,
+    absolute: Vec3,
+    delta_secs: f32,
+) -> Vec3 {
+    let rotation = match axis_damping.space {
+        components::DampingSpace::World => Quat::IDENTITY,
+        components::DampingSpace::TargetLocal => target_rotation,
+    };
+    let error = rotation.inverse() * (absolute - target_point);
+    let step = error * (axis_damping.factors * delta_secs).min(Vec3::ONE);
+
+    target_point + rotation * step
+}
+
+/// Target point moved toward `absolute` according to the framing zones
+fn frame_target_point(
+    framing: &components::FramingZones,
+    camera_rotation: Quat,
+    target_point: Vec3,
+    absolute: Vec3,
+    delta_secs: f32,
+) -> Vec3 {
+    let rotation = match framing.space {
+        components::FramingSpace::World => Quat::IDENTITY,
+        components::FramingSpace::Screen => camera_rotation,
+    };
+    let error = rotation.inverse() * (absolute - target_point);
+
+    // Distance outside of the dead zone, caught up with damping
+    let excess = error.signum() * (error.abs() - framing.dead_zone).max(Vec3::ZERO);
+    let damped = if excess.length_squared() < 1e-8 {
+        excess
+    } else {
+        excess * (delta_secs * framing.damping).min(1.0)
+    };
+    // Whatever is left outside of the soft zone is caught up at once
+    let soft_zone = framing.soft_zone.max(framing.dead_zone);
+    let remaining = error - damped;
+    let step = damped + remaining - remaining.clamp(-soft_zone, soft_zone);
+
+    target_point + rotation * step
+}
+
+/// Returns the smoothed shift of the target point
+fn update_look_ahead(
+    look_ahead: &mut components::LookAhead,
+    target_translation: Vec3,
+    velocity_op: Option<&components::TargetVelocity>,
+    time: &Time,
+) -> Vec3 {
+    let delta_secs = time.delta_secs();
+    let velocity = look_ahead
+        .velocity
+        .update(target_translation, velocity_op, delta_secs)
+        .unwrap_or(Vec3::ZERO);
+
+    let desired = (velocity * look_ahead.time).clamp_length_max(look_ahead.max_distance);
+    let offset = look_ahead
+        .offset
+        .lerp(desired, (delta_secs * look_ahead.smoothing).min(1.0));
+    // Settles instead of approaching forever, the camera is adjusted on every change
+    look_ahead.offset = if offset.distance_squared(desired) < 1e-8 {
+        desired
+    } else {
+        offset
+    };
+    look_ahead.offset
+}
+
+/// Also keeps a damped camera moving after the target has stopped
 pub fn adjust_translation_after_target_s(
     mut commands: Commands,
-    target_q: Query<&components::ThirdPersonCameraTarget, Changed<Transform>>,
+    camera_q: Query<
+        Entity,
+        (
+            With<components::ThirdPersonCamera>,
+            Changed<components::TargetPoint>,
+        ),
+    >,
 ) {
-    for third_person_camera in target_q {
-        for camera in third_person_camera.iter() {
-            commands.trigger(events::AdjustTranslation { camera });
-        }
+    for camera in camera_q {
+        commands.trigger(events::AdjustTranslation { camera });
     }
 }
 
@@ -106,14 +291,17 @@ pub fn mouse_rotation_control_s(
     mut commands: Commands,
     mouse_motion: Res<AccumulatedMouseMotion>,
     camera_settings: Res<ThirdPersonCameraSettings>,
+    replay_q: Query<(), With<InputReplay>>,
 ) {
-    if let Some(camera) = camera_settings.local_cam {
-        if mouse_motion.is_changed() {
-            commands.trigger(events::RotateAroundTarget {
-                camera,
-                delta: mouse_motion.delta * camera_settings.mouse_speed,
-            });
-        }
+    if let Some(camera) = camera_settings.local_cam
+        && !replay_q.contains(camera)
+        && mouse_motion.is_changed()
+        && mouse_motion.delta != Vec2::ZERO
+    {
+        commands.trigger(events::RotateAroundTarget {
+            camera,
+            delta: mouse_motion.delta * camera_settings.mouse_speed,
+        });
     };
 }
 
@@ -122,8 +310,11 @@ pub fn keyboard_rotation_control_s(
     time: Res<Time>,
     keys: Res<ButtonInput<KeyCode>>,
     camera_settings: Res<ThirdPersonCameraSettings>,
+    replay_q: Query<(), With<InputReplay>>,
 ) {
-    if let Some(camera) = camera_settings.local_cam {
+    if let Some(camera) = camera_settings.local_cam
+        && !replay_q.contains(camera)
+    {
         let mut yaw = 0.0;
         let mut pitch = 0.0;
 
@@ -143,7 +334,7 @@ pub fn keyboard_rotation_control_s(
         }
         if yaw != 0.0 || pitch != 0.0 {
             commands.trigger(events::RotateAroundTarget {
-                camera: camera,
+                camera,
                 delta: Vec2::new(yaw, pitch),
             });
         }
@@ -169,8 +360,12 @@ pub fn scroll_zoom_s(
     mut commands: Commands,
     wheel_move: Res<AccumulatedMouseScroll>,
     third_person_cam_settings: Res<ThirdPersonCameraSettings>,
+    replay_q: Query<(), With<InputReplay>>,
 ) {
-    if let Some(camera) = third_person_cam_settings.local_cam {
+    if let Some(camera) = third_person_cam_settings.local_cam
+        && !replay_q.contains(camera)
+        && wheel_move.delta.y != 0.0
+    {
         commands.trigger(events::Zoom {
             camera,
             value: wheel_move.delta.y,
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::{
+    components, confiner::CameraConfiner, follow_2d::Follow2d, free_fly::FreeFly,
+    orbit_override::OrbitOverride,
+};
+
+/// Eases the confined cameras toward the boundary and re-aims them at the target point
+#[allow(clippy::type_complexity)]
+pub fn confine_cameras_s(
+    time: Res<Time>,
+    mut camera_q: Query<(
+        &mut Transform,
+        &mut CameraConfiner,
+        &mut OrbitOverride,
+        Option<&components::TargetPoint>,
+        Has<FreeFly>,
+        Has<Follow2d>,
+    )>,
+) {
+    for (
+        mut camera_transform,
+        mut confiner,
+        mut orbit_override,
+        target_point_op,
+        free_fly,
+        follow_2d,
+    ) in &mut camera_q
+    {
+        let required = confiner.required_correction(camera_transform.translation);
+        let correction = match confiner.smoothing {
+            Some(smoothing) => {
+                let correction = confiner
+                    .correction
+                    .lerp(required, (time.delta_secs() * smoothing).min(1.0));
+                // Settles instead of approaching forever
+                if correction.distance_squared(required) < 1e-8 {
+                    required
+                } else {
+                    correction
+                }
+            }
+            None => required,
+        };
+        confiner.correction = correction;
+        if correction == Vec3::ZERO {
+            continue;
+        }
+
+        orbit_override.stash(&camera_transform);
+        let translation = camera_transform.translation + correction;
+        // The 2D and free-fly cameras keep their rotation
+        if let Some(target_point) = target_point_op
+            && !free_fly
+            && !follow_2d
+        {
+            let from = (target_point.0 - camera_transform.translation).normalize_or_zero();
+            let to = (target_point.0 - translation).normalize_or_zero();
+            if from != Vec3::ZERO && to != Vec3::ZERO {
+                camera_transform.rotation =
+                    Quat::from_rotation_arc(from, to) * camera_transform.rotation;
+            }
+        }
+        camera_transform.translation = translation;
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCameraSettings, components, mode::CameraMode, network::SnapshotBuffer,
+    plugin_settings::DebugOverlayConfig, recording::InputReplay, sequence::SequencePlayer,
+};
+
+pub fn toggle_debug_overlay_s(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay_config: ResMut<DebugOverlayConfig>,
+) {
+    if keys.just_pressed(overlay_config.toggle) {
+        overlay_config.visible = !overlay_config.visible;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn draw_debug_overlay_s(
+    mut gizmos: Gizmos,
+    overlay_config: Res<DebugOverlayConfig>,
+    camera_settings: Res<ThirdPersonCameraSettings>,
+    target_transform_q: Query<&GlobalTransform, With<components::ThirdPersonCameraTarget>>,
+    camera_q: Query<(
+        Entity,
+        &components::ThirdPersonCamera,
+        &GlobalTransform,
+        &components::TargetOffset,
+        &components::TargetPoint,
+        Option<&CameraMode>,
+        Has<InputReplay>,
+        Has<SequencePlayer>,
+        Has<SnapshotBuffer>,
+    )>,
+) {
+    if !overlay_config.visible {
+        return;
+    }
+
+    let mut text = String::new();
+    for (
+        camera_entity,
+        tp_cam,
+        camera_global_transf,
+        target_offset,
+        target_point,
+        mode_op,
+        replayed,
+        sequenced,
+        networked,
+    ) in camera_q
+    {
+        let camera_transf = camera_global_transf.compute_transform();
+        let (yaw, pitch, roll) = camera_transf.rotation.to_euler(EulerRot::YXZ);
+        let distance = camera_transf.translation.distance(target_point.0);
+        let damping_lag = target_transform_q
+            .get(tp_cam.target)
+            .map_or(0.0, |target_transform| {
+                (target_transform.translation() + target_offset.0).distance(target_point.0)
+            });
+        let input = if sequenced {
+            "camera sequence"
+        } else if networked {
+            "network snapshots"
+        } else if replayed {
+            "input replay"
+        } else if camera_settings.local_cam == Some(camera_entity) {
+            "keyboard & mouse"
+        } else {
+            "events"
+        };
+
+        text.clear();
+        let _ = write!(
+            text,
+            "camera {camera_entity} -> target {}\n\
+             yaw {:.1} pitch {:.1} roll {:.1}\n\
+             distance {distance:.2} damping lag {damping_lag:.2}\n\
+             mode {} input {input}",
+            tp_cam.target,
+            yaw.to_degrees(),
+            pitch.to_degrees(),
+            roll.to_degrees(),
+            mode_op.copied().unwrap_or_default().label(),
+        );
+
+        // In front of the camera, facing it
+        gizmos.text(
+            Isometry3d::new(
+                camera_transf.transform_point(overlay_config.offset),
+                camera_transf.rotation,
+            ),
+            &text,
+            overlay_config.font_size,
+            Vec2::new(-0.5, 0.5),
+            overlay_config.color,
+        );
+    }
+}
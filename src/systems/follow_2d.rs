@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use crate::{components, events, follow_2d::Follow2d};
+
+/// The visible area changes after zooming or resizing, keeps the view inside the bounds
+#[allow(clippy::type_complexity)]
+pub fn adjust_2d_view_s(
+    mut commands: Commands,
+    camera_q: Query<
+        Entity,
+        (
+            With<Follow2d>,
+            With<components::TargetPoint>,
+            Changed<Projection>,
+        ),
+    >,
+) {
+    for camera in camera_q {
+        commands.trigger(events::AdjustTranslation { camera });
+    }
+}
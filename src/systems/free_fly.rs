@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCameraSettings,
+    free_fly::{AttachBlend, FreeFly},
+    orbit_override::OrbitOverride,
+};
+
+pub fn free_fly_s(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    camera_settings: Res<ThirdPersonCameraSettings>,
+    mut camera_q: Query<(&mut Transform, &FreeFly)>,
+) {
+    let Some(camera) = camera_settings.local_cam else {
+        return;
+    };
+    let Ok((mut camera_transform, free_fly)) = camera_q.get_mut(camera) else {
+        return;
+    };
+
+    let mut direction = Vec3::ZERO;
+    for (key, key_direction) in [
+        (free_fly.forward, *camera_transform.forward()),
+        (free_fly.back, *camera_transform.back()),
+        (free_fly.left, *camera_transform.left()),
+        (free_fly.right, *camera_transform.right()),
+        (free_fly.ascend, Vec3::Y),
+        (free_fly.descend, Vec3::NEG_Y),
+    ] {
+        if keys.pressed(key) {
+            direction += key_direction;
+        }
+    }
+    camera_transform.translation +=
+        direction.normalize_or_zero() * free_fly.speed * time.delta_secs();
+}
+
+/// Eases the camera from `AttachBlend::from` to its orbit pose, then removes the blend
+pub fn blend_attach_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut blend_q: Query<(Entity, &mut Transform, &mut OrbitOverride, &mut AttachBlend)>,
+) {
+    for (camera, mut camera_transform, mut orbit_override, mut blend) in &mut blend_q {
+        blend.elapsed += time.delta_secs();
+        if blend.elapsed >= blend.duration {
+            commands.entity(camera).remove::<AttachBlend>();
+            continue;
+        }
+
+        let s = EaseFunction::SmoothStep.sample_clamped(blend.elapsed / blend.duration);
+        orbit_override.stash(&camera_transform);
+        let orbit_transform = *camera_transform;
+        camera_transform.translation = blend.from.translation.lerp(orbit_transform.translation, s);
+        camera_transform.rotation = blend.from.rotation.slerp(orbit_transform.rotation, s);
+    }
+}
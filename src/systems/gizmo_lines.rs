@@ -1,14 +1,26 @@
-use bevy::color::palettes::css::WHITE;
+use bevy::{
+    color::palettes::css::{AQUA, FUCHSIA, LIME, ORANGE, WHITE, YELLOW},
+    prelude::*,
+};
+
+use crate::{ThirdPersonCameraSettings, components, plugin_settings::CameraGizmosConfig};
+
+/// Colors of the cameras without `CameraGizmoColor`, in order of their attachment to the target
+const PALETTE: [Srgba; 6] = [WHITE, YELLOW, AQUA, FUCHSIA, LIME, ORANGE];
 
 pub fn draw_relation_gizmo_s(
     mut gizmos: Gizmos,
     third_person_cam_settings: Res<ThirdPersonCameraSettings>,
-    target_global_transf_q: Query<
-        (&GlobalTransform, &components::ThirdPersonCameraTarget),
-        Without<components::ThirdPersonCamera>,
-    >,
+    gizmos_config: Res<CameraGizmosConfig>,
+    // A camera can be the target of another camera, both queries only read
+    target_global_transf_q: Query<(&GlobalTransform, &components::ThirdPersonCameraTarget)>,
     camera_global_transf_q: Query<
-        (&GlobalTransform, &components::TargetOffset),
+        (
+            &GlobalTransform,
+            &components::TargetOffset,
+            &components::TargetPoint,
+            Option<&components::CameraGizmoColor>,
+        ),
         With<components::ThirdPersonCamera>,
     >,
 ) {
@@ -17,17 +29,84 @@ pub fn draw_relation_gizmo_s(
     }
 
     for (target_global_transf, third_person_cam_target) in target_global_transf_q.iter() {
-        let target_transf = target_global_transf.compute_transform();
-        for camera_entity in third_person_cam_target.iter() {
-            if let Ok((camera_global_transf, target_offset)) =
+        let target_translation = target_global_transf.translation();
+        for (index, camera_entity) in third_person_cam_target.iter().enumerate() {
+            let Ok((camera_global_transf, target_offset, target_point, color_op)) =
                 camera_global_transf_q.get(camera_entity)
+            else {
+                continue;
+            };
+            let color = color_op.map_or(PALETTE[index % PALETTE.len()].into(), |color| color.0);
+            let camera_translation = camera_global_transf.translation();
+            let raw_target_point = target_translation + target_offset.0;
+            let distance = camera_translation.distance(target_point.0);
+
+            if gizmos_config.relation_line {
+                gizmos.line(target_point.0, camera_translation, color);
+            }
+            if gizmos_config.orbit_sphere {
+                gizmos
+                    .sphere(target_point.0, distance, color.with_alpha(0.3))
+                    .resolution(32);
+            }
+            if gizmos_config.pitch_limits {
+                for pitch in [
+                    third_person_cam_settings.pitch_min,
+                    third_person_cam_settings.pitch_max,
+                ] {
+                    draw_pitch_limit(&mut gizmos, target_point.0, distance, pitch, color);
+                }
+            }
+            if gizmos_config.target_points {
+                // Cross for the target point without damping, sphere for the damped one
+                gizmos.cross(raw_target_point, 0.3, color);
+                gizmos.sphere(target_point.0, 0.15, color);
+            }
+            if gizmos_config.target_offset {
+                gizmos.line(target_translation, raw_target_point, color.with_alpha(0.5));
+                gizmos.cross(target_translation, 0.15, color.with_alpha(0.5));
+            }
+            if gizmos_config.zoom_range
+                && let Some((zoom_min, zoom_max)) = third_person_cam_settings.zoom_limits
             {
+                let direction = (camera_translation - target_point.0).normalize_or_zero();
+                let rotation = Quat::from_rotation_arc(Vec3::Z, direction);
+                for zoom in [zoom_min, zoom_max] {
+                    gizmos.circle(
+                        Isometry3d::new(target_point.0 + direction * zoom, rotation),
+                        0.25,
+                        color,
+                    );
+                }
                 gizmos.line(
-                    target_transf.translation + target_offset.0,
-                    camera_global_transf.compute_transform().translation,
-                    WHITE,
+                    target_point.0 + direction * zoom_min,
+                    target_point.0 + direction * zoom_max,
+                    color,
                 );
             }
         }
     }
 }
+
+/// Cone of the camera positions at the pitch limit
+fn draw_pitch_limit(gizmos: &mut Gizmos, center: Vec3, distance: f32, pitch: f32, color: Color) {
+    // The camera goes up when looking down
+    let (sin, cos) = (-pitch).sin_cos();
+    let circle_center = center + Vec3::Y * distance * sin;
+    let radius = distance * cos;
+
+    gizmos
+        .circle(
+            Isometry3d::new(circle_center, Quat::from_rotation_arc(Vec3::Z, Vec3::Y)),
+            radius,
+            color.with_alpha(0.5),
+        )
+        .resolution(48);
+    for direction in [Vec3::X, Vec3::NEG_X, Vec3::Z, Vec3::NEG_Z] {
+        gizmos.line(
+            center,
+            circle_center + direction * radius,
+            color.with_alpha(0.5),
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCameraSettings, components,
+    free_fly::FreeFly,
+    ground::{GroundClearance, GroundHeightQuery},
+    orbit_override::OrbitOverride,
+};
+
+/// Pitch offsets tried between the player's pitch and `pitch_min`
+const PITCH_STEPS: usize = 32;
+
+pub fn ground_clearance_s(
+    time: Res<Time>,
+    camera_settings: Res<ThirdPersonCameraSettings>,
+    ground_op: Option<Res<GroundHeightQuery>>,
+    mut clearance_q: Query<
+        (
+            &mut Transform,
+            &mut OrbitOverride,
+            &mut GroundClearance,
+            &components::TargetPoint,
+        ),
+        Without<FreeFly>,
+    >,
+) {
+    let Some(ground) = ground_op else {
+        return;
+    };
+
+    for (mut camera_transform, mut orbit_override, mut clearance, target_point) in &mut clearance_q
+    {
+        // Applied on the final pose, the other overrides may have moved the camera
+        let (yaw, pitch, roll) = camera_transform.rotation.to_euler(EulerRot::YXZ);
+        let camera_offset =
+            camera_transform.rotation.inverse() * (target_point.0 - camera_transform.translation);
+        let pose = |pitch_offset: f32| {
+            let rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch - pitch_offset, roll);
+            (rotation, target_point.0 - rotation * camera_offset)
+        };
+        let is_clear = |translation: Vec3| {
+            ground
+                .0
+                .height_at(translation.xz())
+                .is_none_or(|height| translation.y >= height + clearance.min_height)
+        };
+
+        let max_offset = (pitch - camera_settings.pitch_min).max(0.0);
+        let required = (0..=PITCH_STEPS)
+            .map(|step| step as f32 / PITCH_STEPS as f32 * max_offset)
+            .find(|pitch_offset| is_clear(pose(*pitch_offset).1))
+            .unwrap_or(max_offset);
+
+        let restore_step = clearance.restore_speed * time.delta_secs();
+        clearance.pitch_offset = required.max(clearance.pitch_offset - restore_step);
+        if clearance.pitch_offset <= 0.0 {
+            continue;
+        }
+
+        orbit_override.stash(&camera_transform);
+        (camera_transform.rotation, camera_transform.translation) = pose(clearance.pitch_offset);
+    }
+}
@@ -1,4 +1,23 @@
 pub mod base;
+pub mod confiner;
+pub mod follow_2d;
+pub mod free_fly;
+pub mod ground;
+pub mod mode;
+pub mod network;
+pub mod occlusion;
+pub mod rail;
+pub mod recording;
+pub mod sequence;
+pub mod speed;
+pub mod visibility;
+pub mod volumes;
+
+#[cfg(feature = "debug_overlay")]
+pub mod debug_overlay;
 
 #[cfg(feature = "draw_relation_lines")]
 pub mod gizmo_lines;
+
+#[cfg(feature = "camera_profile")]
+pub mod profile;
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::{components, free_fly::FreeFly, mode::CameraMode, orbit_override::OrbitOverride};
+
+pub fn apply_camera_mode_s(
+    mut mode_q: Query<
+        (
+            &mut Transform,
+            &mut OrbitOverride,
+            &CameraMode,
+            &components::TargetPoint,
+        ),
+        Without<FreeFly>,
+    >,
+) {
+    for (mut camera_transform, mut orbit_override, mode, target_point) in &mut mode_q {
+        let (translation, look_at) = match *mode {
+            CameraMode::Orbit => continue,
+            CameraMode::Fixed { position } => (position, target_point.0),
+            CameraMode::LookAtPoint { point } => (camera_transform.translation, point),
+        };
+
+        orbit_override.stash(&camera_transform);
+        *camera_transform = Transform::from_translation(translation).looking_at(look_at, Vec3::Y);
+    }
+}
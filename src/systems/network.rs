@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{
+    components, events,
+    network::{NetworkTargetId, SnapshotBuffer},
+};
+
+pub fn apply_snapshots_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut camera_q: Query<(
+        Entity,
+        &mut SnapshotBuffer,
+        &mut Transform,
+        &mut components::CameraOffset,
+        &components::ThirdPersonCamera,
+    )>,
+    target_id_q: Query<(Entity, &NetworkTargetId)>,
+) {
+    for (camera, mut buffer, mut camera_transform, mut camera_offset, tp_cam) in &mut camera_q {
+        let Some(clock) = buffer.clock.as_mut() else {
+            continue;
+        };
+        *clock += time.delta_secs();
+        let render_time = *clock - buffer.delay;
+        let Some(snapshot) = buffer.sample(render_time) else {
+            continue;
+        };
+
+        camera_transform.rotation =
+            Quat::from_euler(EulerRot::YXZ, snapshot.yaw, snapshot.pitch, snapshot.roll);
+        camera_offset.0 = camera_offset.0.normalize_or(Vec3::NEG_Z) * snapshot.distance;
+        if target_id_q
+            .get(tp_cam.target)
+            .ok()
+            .is_none_or(|(_, target_id)| target_id.0 != snapshot.target)
+        {
+            match target_id_q
+                .iter()
+                .find(|(_, target_id)| target_id.0 == snapshot.target)
+            {
+                Some((target, _)) => {
+                    commands
+                        .entity(camera)
+                        .insert(components::ThirdPersonCamera::aimed_at(target));
+                }
+                None => debug!("{} no target with the id {}", camera, snapshot.target),
+            }
+        }
+        commands.trigger(events::AdjustTranslation { camera });
+    }
+}
@@ -0,0 +1,142 @@
+use bevy::{
+    ecs::entity::EntityHashSet,
+    math::bounding::{Aabb3d, BoundingSphere, RayCast3d},
+    prelude::*,
+};
+
+use crate::{
+    components, events,
+    occlusion::{CameraOccluders, Occluder, OccluderFade, OccluderShape, OccludingTarget},
+};
+
+/// Whether the segment between `from` and `to` crosses the occluder
+pub(crate) fn intersects(
+    occluder: &Occluder,
+    occluder_transform: &GlobalTransform,
+    from: Vec3,
+    to: Vec3,
+) -> bool {
+    let world_to_local = occluder_transform.affine().inverse();
+    let from = world_to_local.transform_point3a(from.into());
+    let to = world_to_local.transform_point3a(to.into());
+    let Ok(direction) = Dir3A::new(to - from) else {
+        return false;
+    };
+    let ray_cast = RayCast3d::new(from, direction, from.distance(to));
+
+    match occluder.0 {
+        OccluderShape::Aabb { half_size } => ray_cast
+            .aabb_intersection_at(&Aabb3d::new(Vec3::ZERO, half_size))
+            .is_some(),
+        OccluderShape::Sphere { radius } => ray_cast
+            .sphere_intersection_at(&BoundingSphere::new(Vec3::ZERO, radius))
+            .is_some(),
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn detect_occluders_s(
+    mut commands: Commands,
+    mut camera_q: Query<(
+        Entity,
+        &GlobalTransform,
+        &components::TargetPoint,
+        &components::ThirdPersonCamera,
+        Option<&mut CameraOccluders>,
+    )>,
+    mut occluder_q: Query<(
+        Entity,
+        &Occluder,
+        &GlobalTransform,
+        Option<&mut OccludingTarget>,
+    )>,
+) {
+    let mut occluding_any = EntityHashSet::default();
+    for (camera, camera_transform, target_point, tp_cam, occluders_op) in &mut camera_q {
+        // The target's own shape isn't in the way
+        let occluders: Vec<Entity> = occluder_q
+            .iter()
+            .filter(|(occluder_entity, occluder, occluder_transform, _)| {
+                *occluder_entity != tp_cam.target
+                    && intersects(
+                        occluder,
+                        occluder_transform,
+                        camera_transform.translation(),
+                        target_point.0,
+                    )
+            })
+            .map(|(occluder_entity, ..)| occluder_entity)
+            .collect();
+
+        let previous = occluders_op
+            .as_deref()
+            .map_or(&[][..], |previous| &previous.0);
+        for occluder in occluders
+            .iter()
+            .filter(|occluder| !previous.contains(occluder))
+        {
+            commands.trigger(events::CameraCollided {
+                camera,
+                occluder: *occluder,
+            });
+        }
+        occluding_any.extend(occluders.iter().copied());
+
+        match occluders_op {
+            Some(mut camera_occluders) => {
+                camera_occluders.set_if_neq(CameraOccluders(occluders));
+            }
+            None if !occluders.is_empty() => {
+                commands.entity(camera).insert(CameraOccluders(occluders));
+            }
+            None => {}
+        }
+    }
+
+    for (occluder_entity, _, _, occluding_op) in &mut occluder_q {
+        let occluding = occluding_any.contains(&occluder_entity);
+        match occluding_op {
+            Some(mut occluding_target) => {
+                occluding_target.set_if_neq(OccludingTarget {
+                    occluding,
+                    ..*occluding_target
+                });
+            }
+            None if occluding => {
+                commands.entity(occluder_entity).insert(OccludingTarget {
+                    alpha: 1.0,
+                    occluding,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+pub fn fade_occluders_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    occluder_fade: Res<OccluderFade>,
+    mut occluding_q: Query<(Entity, &mut OccludingTarget)>,
+) {
+    for (occluder_entity, mut occluding_target) in &mut occluding_q {
+        let target_alpha = if occluding_target.occluding {
+            occluder_fade.alpha
+        } else {
+            1.0
+        };
+        let step = occluder_fade.speed * time.delta_secs();
+        let alpha =
+            occluding_target.alpha + (target_alpha - occluding_target.alpha).clamp(-step, step);
+
+        if alpha != occluding_target.alpha {
+            occluding_target.alpha = alpha;
+            if let Some(on_fade) = occluder_fade.on_fade {
+                on_fade(&mut commands.entity(occluder_entity), alpha);
+            }
+        }
+        if !occluding_target.occluding && alpha >= 1.0 {
+            commands.entity(occluder_entity).remove::<OccludingTarget>();
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCameraSettings, components, events,
+    profile::{CameraProfile, SettingsProfile, ThirdPersonCameraProfile},
+};
+
+fn reloaded_id(
+    event: &AssetEvent<ThirdPersonCameraProfile>,
+) -> Option<AssetId<ThirdPersonCameraProfile>> {
+    match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+        _ => None,
+    }
+}
+
+pub fn apply_settings_profile_s(
+    mut profile_events: MessageReader<AssetEvent<ThirdPersonCameraProfile>>,
+    settings_profile: Option<Res<SettingsProfile>>,
+    profiles: Res<Assets<ThirdPersonCameraProfile>>,
+    mut camera_settings: ResMut<ThirdPersonCameraSettings>,
+) {
+    let Some(settings_profile) = settings_profile else {
+        profile_events.clear();
+        return;
+    };
+
+    let reloaded = profile_events
+        .read()
+        .filter_map(reloaded_id)
+        .any(|id| id == settings_profile.0.id());
+
+    if (reloaded || settings_profile.is_changed())
+        && let Some(profile) = profiles.get(&settings_profile.0)
+    {
+        debug!("apply settings profile {:?}", settings_profile.0.path());
+        profile.apply_to_settings(&mut camera_settings);
+    }
+}
+
+pub fn apply_camera_profile_s(
+    mut commands: Commands,
+    mut profile_events: MessageReader<AssetEvent<ThirdPersonCameraProfile>>,
+    profiles: Res<Assets<ThirdPersonCameraProfile>>,
+    camera_profile_q: Query<(Entity, Ref<CameraProfile>, Has<components::TargetPoint>)>,
+) {
+    let reloaded: Vec<_> = profile_events.read().filter_map(reloaded_id).collect();
+
+    for (camera_entity, camera_profile, has_target_point) in camera_profile_q {
+        if !camera_profile.is_changed() && !reloaded.contains(&camera_profile.0.id()) {
+            continue;
+        }
+        if let Some(profile) = profiles.get(&camera_profile.0) {
+            debug!(
+                "apply camera profile {:?} to {}",
+                camera_profile.0.path(),
+                camera_entity
+            );
+            profile.apply_to_camera(&mut commands.entity(camera_entity));
+            // Placed with the new offset right away, not on the next input
+            if has_target_point && profile.camera_offset.is_some() {
+                commands.trigger(events::AdjustTranslation {
+                    camera: camera_entity,
+                });
+            }
+        }
+    }
+}
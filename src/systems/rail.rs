@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+use crate::{components, orbit_override::OrbitOverride, rail};
+
+/// Number of samples per curve segment to find the point closest to the target
+const NEAREST_SAMPLES: usize = 32;
+
+pub fn follow_rail_s(
+    time: Res<Time>,
+    mut rail_q: Query<(
+        &mut Transform,
+        &mut OrbitOverride,
+        &mut rail::CameraRail,
+        &components::TargetPoint,
+    )>,
+) {
+    for (mut camera_transform, mut orbit_override, mut rail, target_point) in &mut rail_q {
+        let max_t = rail.curve.segments().len() as f32;
+        let blend_step = rail.blend_speed * time.delta_secs();
+        rail.weight += (rail.target_weight - rail.weight).clamp(-blend_step, blend_step);
+
+        rail.t = match rail.parameterization {
+            rail::RailParameterization::Time { speed, looping } => {
+                let t = rail.t + speed * time.delta_secs();
+                if looping {
+                    t.rem_euclid(max_t)
+                } else {
+                    t.clamp(0.0, max_t)
+                }
+            }
+            rail::RailParameterization::NearestToTarget => nearest_t(&rail.curve, target_point.0),
+        };
+
+        if rail.weight <= 0.0 {
+            continue;
+        }
+
+        orbit_override.stash(&camera_transform);
+        let orbit_transform = *camera_transform;
+        let translation = orbit_transform
+            .translation
+            .lerp(rail.curve.position(rail.t), rail.weight);
+        let look_at_target = Transform::from_translation(translation)
+            .looking_at(target_point.0, Vec3::Y)
+            .rotation;
+
+        camera_transform.translation = translation;
+        camera_transform.rotation = orbit_transform.rotation.slerp(look_at_target, rail.weight);
+    }
+}
+
+fn nearest_t(curve: &CubicCurve<Vec3>, point: Vec3) -> f32 {
+    let subdivisions = curve.segments().len() * NEAREST_SAMPLES;
+    let max_t = curve.segments().len() as f32;
+
+    (0..=subdivisions)
+        .map(|i| i as f32 / subdivisions as f32 * max_t)
+        .min_by(|a, b| {
+            let distance_a = curve.position(*a).distance_squared(point);
+            let distance_b = curve.position(*b).distance_squared(point);
+            distance_a.total_cmp(&distance_b)
+        })
+        .unwrap_or_default()
+}
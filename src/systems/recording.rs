@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::{
+    events,
+    recording::{InputReplay, RecordedInput},
+};
+
+pub fn replay_input_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut replay_q: Query<(Entity, &mut InputReplay)>,
+) {
+    for (camera, mut replay) in &mut replay_q {
+        let elapsed = time.elapsed().saturating_sub(replay.started).as_secs_f32();
+
+        while let Some(recorded) = replay.log.events.get(replay.next)
+            && recorded.time <= elapsed
+        {
+            match recorded.input {
+                RecordedInput::Rotate(delta) => {
+                    commands.trigger(events::RotateAroundTarget { camera, delta })
+                }
+                RecordedInput::Zoom(value) => commands.trigger(events::Zoom { camera, value }),
+                RecordedInput::Roll(value) => commands.trigger(events::Roll { camera, value }),
+                // Would take the player's input over to the replayed camera
+                RecordedInput::SetLocalCamera => {
+                    debug!("{} replay skips SetLocalCamera", camera);
+                }
+            }
+            replay.next += 1;
+        }
+
+        if replay.next >= replay.log.events.len() {
+            commands.entity(camera).remove::<InputReplay>();
+            commands.trigger(events::ReplayFinished { camera });
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::{components, events, sequence::SequencePlayer};
+
+#[allow(clippy::type_complexity)]
+pub fn play_sequence_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut camera_q: Query<(
+        Entity,
+        &mut SequencePlayer,
+        &mut Transform,
+        &mut components::CameraOffset,
+        &mut components::TargetOffset,
+        Option<&mut Projection>,
+    )>,
+) {
+    for (
+        camera,
+        mut player,
+        mut camera_transform,
+        mut camera_offset,
+        mut target_offset,
+        projection_op,
+    ) in &mut camera_q
+    {
+        if player.paused {
+            continue;
+        }
+        let skipped = player.elapsed.is_infinite();
+        player.elapsed += time.delta_secs();
+
+        let state = player.sequence.sample(&player.start, player.elapsed);
+        state.apply(
+            &mut camera_transform,
+            &mut camera_offset,
+            &mut target_offset,
+            projection_op.map(Mut::into_inner),
+        );
+        commands.trigger(events::AdjustTranslation { camera });
+
+        if player.is_finished() {
+            camera_offset.0 = player.start_camera_offset;
+            commands.entity(camera).remove::<SequencePlayer>();
+            commands.trigger(events::SequenceFinished { camera, skipped });
+        }
+    }
+}
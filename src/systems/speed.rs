@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::{components, orbit_override::OrbitOverride, speed::SpeedEffects};
+
+/// Pushes the camera back along its view and widens the field of view on top of the orbit
+#[allow(clippy::type_complexity)]
+pub fn apply_speed_effects_s(
+    time: Res<Time>,
+    target_q: Query<(&GlobalTransform, Option<&components::TargetVelocity>)>,
+    mut camera_q: Query<(
+        &mut Transform,
+        &mut SpeedEffects,
+        &mut OrbitOverride,
+        &components::ThirdPersonCamera,
+        &components::TargetPoint,
+        Option<&mut Projection>,
+    )>,
+) {
+    let delta_secs = time.delta_secs();
+    for (
+        mut camera_transform,
+        mut effects,
+        mut orbit_override,
+        tp_cam,
+        target_point,
+        projection_op,
+    ) in &mut camera_q
+    {
+        let Ok((target_transform, velocity_op)) = target_q.get(tp_cam.target) else {
+            continue;
+        };
+        let speed = effects
+            .velocity
+            .update(target_transform.translation(), velocity_op, delta_secs)
+            .map_or(effects.speed, Vec3::length);
+        let smoothed = effects
+            .speed
+            .lerp(speed, (delta_secs * effects.smoothing).min(1.0));
+        // Settles instead of approaching forever
+        effects.speed = if (smoothed - speed).abs() < 1e-3 {
+            speed
+        } else {
+            smoothed
+        };
+
+        let fov = effects.fov.sample(effects.speed);
+        if fov != 0.0
+            && let Some(mut projection) = projection_op
+            && let Projection::Perspective(perspective) = projection.as_mut()
+        {
+            orbit_override.stash_fov(perspective.fov);
+            perspective.fov += fov;
+        }
+
+        let distance = effects.distance.sample(effects.speed);
+        if distance != 0.0 {
+            orbit_override.stash(&camera_transform);
+            let direction = (camera_transform.translation - target_point.0).normalize_or_zero();
+            camera_transform.translation += direction * distance;
+        }
+    }
+}
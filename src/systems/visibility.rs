@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::{
+    ThirdPersonCameraSettings, components, events,
+    occlusion::Occluder,
+    systems::occlusion::intersects,
+    visibility::{LineOfSightQuery, TargetVisibility},
+};
+
+fn is_on_screen(camera_transform: &GlobalTransform, projection: &Projection, point: Vec3) -> bool {
+    let local = camera_transform.affine().inverse().transform_point3(point);
+    let depth = -local.z;
+    match projection {
+        Projection::Perspective(perspective) => {
+            let half_height = (perspective.fov / 2.0).tan() * depth;
+            depth > 0.0
+                && local.y.abs() <= half_height
+                && local.x.abs() <= half_height * perspective.aspect_ratio
+        }
+        Projection::Orthographic(orthographic) => {
+            (orthographic.near..=orthographic.far).contains(&depth)
+                && orthographic.area.contains(local.truncate())
+        }
+        Projection::Custom(_) => true,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_target_visibility_s(
+    mut commands: Commands,
+    camera_settings: Res<ThirdPersonCameraSettings>,
+    line_of_sight_op: Option<Res<LineOfSightQuery>>,
+    mut camera_q: Query<(
+        Entity,
+        &mut TargetVisibility,
+        &GlobalTransform,
+        &components::TargetPoint,
+        &components::ThirdPersonCamera,
+        Option<&Projection>,
+    )>,
+    occluder_q: Query<(Entity, &Occluder, &GlobalTransform)>,
+) {
+    for (camera, mut visibility, camera_transform, target_point, tp_cam, projection_op) in
+        &mut camera_q
+    {
+        let from = camera_transform.translation();
+        let is_blocked = |to: Vec3| match &line_of_sight_op {
+            Some(line_of_sight) => line_of_sight.0.is_blocked(from, to, tp_cam.target),
+            None => occluder_q
+                .iter()
+                .any(|(occluder_entity, occluder, occluder_transform)| {
+                    occluder_entity != tp_cam.target
+                        && intersects(occluder, occluder_transform, from, to)
+                }),
+        };
+
+        let new_visibility = if projection_op
+            .is_some_and(|projection| !is_on_screen(camera_transform, projection, target_point.0))
+        {
+            TargetVisibility::OffScreen
+        } else {
+            let radius = camera_settings.visibility_sample_radius;
+            let right = camera_transform.right() * radius;
+            let up = camera_transform.up() * radius;
+            let blocked = [Vec3::ZERO, right, -right, up, -up]
+                .into_iter()
+                .filter(|offset| is_blocked(target_point.0 + *offset))
+                .count();
+            match blocked {
+                0 => TargetVisibility::Visible,
+                5 => TargetVisibility::Occluded,
+                _ => TargetVisibility::PartiallyOccluded,
+            }
+        };
+
+        if visibility.set_if_neq(new_visibility) {
+            commands.trigger(events::TargetVisibilityChanged {
+                camera,
+                visibility: new_visibility,
+            });
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+use crate::{
+    components,
+    free_fly::FreeFly,
+    math::{clamp_angle, lerp_angle},
+    orbit_override::OrbitOverride,
+    volumes::{CameraVolume, VolumeBlend},
+};
+
+#[allow(clippy::type_complexity)]
+pub fn apply_camera_volumes_s(
+    mut commands: Commands,
+    time: Res<Time>,
+    volume_q: Query<(Entity, &CameraVolume, &GlobalTransform)>,
+    target_q: Query<&GlobalTransform, With<components::ThirdPersonCameraTarget>>,
+    mut camera_q: Query<
+        (
+            Entity,
+            &mut Transform,
+            &components::ThirdPersonCamera,
+            &components::TargetPoint,
+            &components::CameraOffset,
+            &components::TargetOffset,
+            Option<(&mut VolumeBlend, &mut OrbitOverride)>,
+        ),
+        Without<FreeFly>,
+    >,
+) {
+    for (
+        camera,
+        mut camera_transform,
+        tp_cam,
+        target_point,
+        camera_offset,
+        target_offset,
+        blend_op,
+    ) in &mut camera_q
+    {
+        let Ok(target_transform) = target_q.get(tp_cam.target) else {
+            continue;
+        };
+        let target_translation = target_transform.translation();
+        let inside = |volume: &CameraVolume, volume_transform: &GlobalTransform| {
+            let local = volume_transform
+                .affine()
+                .inverse()
+                .transform_point3(target_translation);
+            volume.shape.contains(local)
+        };
+
+        let Some((mut blend, mut orbit_override)) = blend_op else {
+            if volume_q
+                .iter()
+                .any(|(_, volume, volume_transform)| inside(volume, volume_transform))
+            {
+                commands.entity(camera).insert(VolumeBlend::default());
+            }
+            continue;
+        };
+
+        for (volume_entity, volume, volume_transform) in &volume_q {
+            let is_inside = inside(volume, volume_transform);
+            let index = blend
+                .weights
+                .iter()
+                .position(|(entity, _)| *entity == volume_entity);
+            let weight = index.map_or(0.0, |index| blend.weights[index].1);
+            let new_weight = if is_inside {
+                weight + time.delta_secs() / volume.blend_in.max(f32::EPSILON)
+            } else {
+                weight - time.delta_secs() / volume.blend_out.max(f32::EPSILON)
+            }
+            .clamp(0.0, 1.0);
+            match index {
+                Some(index) => blend.weights[index].1 = new_weight,
+                None if is_inside => blend.weights.push((volume_entity, new_weight)),
+                None => {}
+            }
+        }
+        // Left or despawned volumes
+        blend.weights.retain(|(entity, weight)| {
+            volume_q
+                .get(*entity)
+                .is_ok_and(|(_, volume, transform)| *weight > 0.0 || inside(volume, transform))
+        });
+
+        let mut volumes: Vec<_> = blend
+            .weights
+            .iter()
+            .filter(|(_, weight)| *weight > 0.0)
+            .filter_map(|(entity, weight)| {
+                volume_q
+                    .get(*entity)
+                    .ok()
+                    .map(|(_, volume, _)| (volume, *weight))
+            })
+            .collect();
+        if volumes.is_empty() {
+            continue;
+        }
+        volumes.sort_by_key(|(volume, _)| volume.priority);
+
+        let (mut yaw, mut pitch, roll) = camera_transform.rotation.to_euler(EulerRot::YXZ);
+        let mut distance = camera_offset.0.length();
+        let mut offset = target_offset.0;
+        for (volume, weight) in volumes {
+            let overrides = &volume.overrides;
+            if let Some(override_distance) = overrides.distance {
+                distance = distance.lerp(override_distance, weight);
+            }
+            if let Some(override_pitch) = overrides.pitch {
+                pitch = pitch.lerp(override_pitch, weight);
+            }
+            if let Some(override_yaw) = overrides.yaw {
+                yaw = lerp_angle(yaw, override_yaw, weight);
+            }
+            if let Some((yaw_min, yaw_max)) = overrides.yaw_limits {
+                yaw = lerp_angle(yaw, clamp_angle(yaw, yaw_min, yaw_max), weight);
+            }
+            if let Some(override_offset) = overrides.target_offset {
+                offset = offset.lerp(override_offset, weight);
+            }
+        }
+
+        orbit_override.stash(&camera_transform);
+        let rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch, roll);
+        let volume_target_point = target_point.0 + offset - target_offset.0;
+        camera_transform.rotation = rotation;
+        camera_transform.translation =
+            volume_target_point - rotation * camera_offset.0.normalize_or(Vec3::NEG_Z) * distance;
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// What the camera does when its target is despawned.
+/// Overrides `ThirdPersonCameraSettings::default_target_loss_policy` for a specific camera.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum TargetLossPolicy {
+    /// Stay where the target was lost
+    #[default]
+    Hold,
+    /// Aim at another target
+    Fallback(#[entities] Entity),
+}
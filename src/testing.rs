@@ -0,0 +1,294 @@
+//! Headless helpers for deterministic tests of camera behavior,
+//! no window or GPU required.
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+//!
+//! let CameraFixture {
+//!     mut app,
+//!     camera,
+//!     target,
+//! } = camera_fixture().local().build();
+//! assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+//!
+//! app.move_to(target, Vec3::new(4.0, 1.0, 0.0)).step(2);
+//! assert_camera_at(&app, camera, Vec3::new(4.0, 1.0, 15.0), 1e-4);
+//!
+//! app.scroll(3.0).step(1);
+//! assert!((camera_distance(&app, camera) - 12.0).abs() < 1e-4);
+//!
+//! // Without the fixture
+//! let mut app = headless_app(tp_cam::ThirdPersonCameraSettings::default());
+//! let target = app.world_mut().spawn(Transform::from_xyz(0.0, 1.0, 0.0)).id();
+//! let camera = app
+//!     .world_mut()
+//!     .spawn((Transform::default(), tp_cam::ThirdPersonCamera::aimed_at(target)))
+//!     .id();
+//! app.step(1);
+//! assert_camera_looks_at(&app, camera, Vec3::new(0.0, 1.0, 0.0), 1e-4);
+//! ```
+
+use core::time::Duration;
+
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput, NativeKey},
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        touch::TouchPhase,
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+
+use crate::{ThirdPersonCameraPlugin, ThirdPersonCameraSettings, components, events};
+
+/// Duration of a single [`CameraTestExt::step`]
+pub const FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// Build an [`App`] with [`MinimalPlugins`], input, transforms and the [`ThirdPersonCameraPlugin`].
+/// Time advances by [`FRAME_TIME`] on every update.
+pub fn headless_app(settings: ThirdPersonCameraSettings) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, TransformPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME_TIME));
+
+    #[cfg(any(
+        feature = "camera_profile",
+        feature = "draw_relation_lines",
+        feature = "debug_overlay"
+    ))]
+    app.add_plugins(AssetPlugin::default());
+    // Only what the debug gizmos need, the full GizmoPlugin depends on the mesh assets
+    #[cfg(any(feature = "draw_relation_lines", feature = "debug_overlay"))]
+    {
+        use bevy::gizmos::{AppGizmoBuilder, GizmoAsset};
+        app.init_asset::<GizmoAsset>()
+            .init_gizmo_group::<DefaultGizmoConfigGroup>();
+    }
+
+    app.add_plugins(ThirdPersonCameraPlugin::new(settings));
+    app
+}
+
+/// Headless app with a target and a camera aimed at it, built by [`camera_fixture`]
+pub struct CameraFixture {
+    pub app: App,
+    pub camera: Entity,
+    pub target: Entity,
+}
+
+type AppSetup = Box<dyn FnOnce(&mut App)>;
+type EntitySetup = Box<dyn FnOnce(&mut EntityWorldMut)>;
+
+/// Target at `(0, 1, 0)` and a camera aimed at it, with default settings, updated once
+pub fn camera_fixture() -> CameraFixtureBuilder {
+    CameraFixtureBuilder {
+        settings: ThirdPersonCameraSettings::default(),
+        setup: Vec::new(),
+        target: Vec::new(),
+        camera: Vec::new(),
+        local: false,
+        frames: 1,
+    }
+}
+
+pub struct CameraFixtureBuilder {
+    settings: ThirdPersonCameraSettings,
+    setup: Vec<AppSetup>,
+    target: Vec<EntitySetup>,
+    camera: Vec<EntitySetup>,
+    local: bool,
+    frames: u32,
+}
+
+impl CameraFixtureBuilder {
+    pub fn settings(mut self, settings: ThirdPersonCameraSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Run on the app before the target and the camera are spawned
+    pub fn setup(mut self, setup: impl FnOnce(&mut App) + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    /// Extra components of the target, a `Transform` replaces the default one
+    pub fn target(mut self, bundle: impl Bundle) -> Self {
+        self.target.push(Box::new(move |target| {
+            target.insert(bundle);
+        }));
+        self
+    }
+
+    /// Extra components of the camera, a `Transform` replaces the default one
+    pub fn camera(mut self, bundle: impl Bundle) -> Self {
+        self.camera.push(Box::new(move |camera| {
+            camera.insert(bundle);
+        }));
+        self
+    }
+
+    /// Controlled by the simulated mouse and keyboard
+    pub fn local(mut self) -> Self {
+        self.local = true;
+        self
+    }
+
+    /// Updates run once spawned
+    pub fn step(mut self, frames: u32) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    pub fn build(self) -> CameraFixture {
+        let mut app = headless_app(self.settings);
+        for setup in self.setup {
+            setup(&mut app);
+        }
+
+        let mut target = app.world_mut().spawn(Transform::from_xyz(0.0, 1.0, 0.0));
+        for setup in self.target {
+            setup(&mut target);
+        }
+        let target = target.id();
+
+        let mut camera = app.world_mut().spawn((
+            Transform::default(),
+            components::ThirdPersonCamera::aimed_at(target),
+        ));
+        for setup in self.camera {
+            setup(&mut camera);
+        }
+        let camera = camera.id();
+
+        if self.local {
+            app.world_mut().trigger(events::SetLocalCamera(camera));
+        }
+        app.step(self.frames);
+        CameraFixture {
+            app,
+            camera,
+            target,
+        }
+    }
+}
+
+/// Simulated input and time stepping
+pub trait CameraTestExt {
+    /// Run `frames` updates
+    fn step(&mut self, frames: u32) -> &mut Self;
+
+    /// Mouse movement processed on the next update
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self;
+
+    /// Mouse wheel scrolling processed on the next update
+    fn scroll(&mut self, lines: f32) -> &mut Self;
+
+    /// Key press processed on the next update, the key stays pressed until released
+    fn press_key(&mut self, key: KeyCode) -> &mut Self;
+    fn release_key(&mut self, key: KeyCode) -> &mut Self;
+
+    /// Set the translation of the target (or any other entity)
+    fn move_to(&mut self, entity: Entity, translation: Vec3) -> &mut Self;
+
+    fn camera_transform(&self, camera: Entity) -> Transform;
+}
+
+impl CameraTestExt for App {
+    fn step(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.update();
+        }
+        self
+    }
+
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.world_mut().write_message(MouseMotion { delta });
+        self
+    }
+
+    fn scroll(&mut self, lines: f32) -> &mut Self {
+        self.world_mut().write_message(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: lines,
+            window: Entity::PLACEHOLDER,
+            phase: TouchPhase::Moved,
+        });
+        self
+    }
+
+    fn press_key(&mut self, key: KeyCode) -> &mut Self {
+        self.world_mut()
+            .write_message(keyboard_input(key, ButtonState::Pressed));
+        self
+    }
+
+    fn release_key(&mut self, key: KeyCode) -> &mut Self {
+        self.world_mut()
+            .write_message(keyboard_input(key, ButtonState::Released));
+        self
+    }
+
+    fn move_to(&mut self, entity: Entity, translation: Vec3) -> &mut Self {
+        self.world_mut()
+            .get_mut::<Transform>(entity)
+            .expect("entity has no Transform")
+            .translation = translation;
+        self
+    }
+
+    fn camera_transform(&self, camera: Entity) -> Transform {
+        *self
+            .world()
+            .get::<Transform>(camera)
+            .expect("camera has no Transform")
+    }
+}
+
+fn keyboard_input(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+#[track_caller]
+pub fn assert_vec3_near(actual: Vec3, expected: Vec3, epsilon: f32) {
+    assert!(
+        actual.abs_diff_eq(expected, epsilon),
+        "expected {expected}, got {actual} (epsilon {epsilon})"
+    );
+}
+
+#[track_caller]
+pub fn assert_camera_at(app: &App, camera: Entity, expected: Vec3, epsilon: f32) {
+    assert_vec3_near(app.camera_transform(camera).translation, expected, epsilon);
+}
+
+/// Checks the camera's forward direction passes through `point`
+#[track_caller]
+pub fn assert_camera_looks_at(app: &App, camera: Entity, point: Vec3, epsilon: f32) {
+    let transform = app.camera_transform(camera);
+    let expected = (point - transform.translation).normalize();
+    assert_vec3_near(*transform.forward(), expected, epsilon);
+}
+
+/// Distance between the camera and its [`TargetPoint`](components::TargetPoint)
+#[track_caller]
+pub fn camera_distance(app: &App, camera: Entity) -> f32 {
+    let target_point = app
+        .world()
+        .get::<components::TargetPoint>(camera)
+        .expect("camera has no TargetPoint");
+    app.camera_transform(camera)
+        .translation
+        .distance(target_point.0)
+}
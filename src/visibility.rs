@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Whether the camera sees its target point, updated every frame when inserted on a camera.
+/// The target point is tested with a ray from the camera, and four more rays
+/// `ThirdPersonCameraSettings::visibility_sample_radius` around it tell partial occlusion.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum TargetVisibility {
+    #[default]
+    Visible,
+    PartiallyOccluded,
+    Occluded,
+    /// Outside the view of a perspective or orthographic camera
+    OffScreen,
+}
+
+/// Ray casts for `TargetVisibility`, implemented for closures
+pub trait LineOfSight: Send + Sync + 'static {
+    /// Whether something other than `target` blocks the segment from `from` to `to`
+    fn is_blocked(&self, from: Vec3, to: Vec3, target: Entity) -> bool;
+}
+
+impl<F> LineOfSight for F
+where
+    F: Fn(Vec3, Vec3, Entity) -> bool + Send + Sync + 'static,
+{
+    fn is_blocked(&self, from: Vec3, to: Vec3, target: Entity) -> bool {
+        self(from, to, target)
+    }
+}
+
+/// Backend of the `TargetVisibility` ray casts, the `Occluder` shapes are used without it
+#[derive(Resource)]
+pub struct LineOfSightQuery(pub Box<dyn LineOfSight>);
+
+impl LineOfSightQuery {
+    pub fn new(line_of_sight: impl LineOfSight) -> Self {
+        Self(Box::new(line_of_sight))
+    }
+}
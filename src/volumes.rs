@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::orbit_override::OrbitOverride;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Shape in the local space of the `CameraVolume`
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub enum VolumeShape {
+    Aabb { half_size: Vec3 },
+    Sphere { radius: f32 },
+}
+
+impl VolumeShape {
+    pub fn contains(&self, local_point: Vec3) -> bool {
+        match self {
+            Self::Aabb { half_size } => local_point.abs().cmple(*half_size).all(),
+            Self::Sphere { radius } => local_point.length_squared() <= radius * radius,
+        }
+    }
+}
+
+/// Camera parameters replaced inside a `CameraVolume`, angles in radians
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraOverrides {
+    /// Distance between the camera and the target point
+    pub distance: Option<f32>,
+    pub pitch: Option<f32>,
+    /// Fixed angle when used with `pitch`
+    pub yaw: Option<f32>,
+    /// Minimum and maximum yaw, counterclockwise from the minimum so the range may cross ±π
+    pub yaw_limits: Option<(f32, f32)>,
+    pub target_offset: Option<Vec3>,
+}
+
+/// Overrides the parameters of the cameras while their target is inside the volume.
+/// Overlapping volumes are applied from the lowest priority to the highest.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct CameraVolume {
+    pub shape: VolumeShape,
+    pub overrides: CameraOverrides,
+    pub priority: i32,
+    /// Seconds to fully apply the overrides once the target is inside
+    pub blend_in: f32,
+    /// Seconds to return to the camera's parameters once the target has left
+    pub blend_out: f32,
+}
+
+impl CameraVolume {
+    /// Blends in and out in half a second
+    pub fn new(shape: VolumeShape, overrides: CameraOverrides) -> Self {
+        Self {
+            shape,
+            overrides,
+            priority: 0,
+            blend_in: 0.5,
+            blend_out: 0.5,
+        }
+    }
+}
+
+/// Weights of the volumes influencing the camera, inserted when its target enters one
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Debug, Clone, Default)]
+#[require(OrbitOverride)]
+pub struct VolumeBlend {
+    /// Volume entities with their weight, from 0 to 1
+    pub weights: Vec<(Entity, f32)>,
+}
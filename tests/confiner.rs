@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn confined_app(confiner: tp_cam::CameraConfiner) -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture().camera(confiner).local().build();
+    (app, camera)
+}
+
+#[test]
+fn closest_point_in_shapes() {
+    let aabb = tp_cam::ConfinerShape::Aabb {
+        min: Vec3::splat(-1.0),
+        max: Vec3::splat(1.0),
+    };
+    assert_vec3_near(
+        aabb.closest_point(Vec3::new(3.0, 0.5, -2.0)),
+        Vec3::new(1.0, 0.5, -1.0),
+        1e-6,
+    );
+
+    let sphere = tp_cam::ConfinerShape::Sphere {
+        center: Vec3::X,
+        radius: 2.0,
+    };
+    assert_vec3_near(
+        sphere.closest_point(Vec3::new(5.0, 0.0, 0.0)),
+        Vec3::new(3.0, 0.0, 0.0),
+        1e-6,
+    );
+    assert_vec3_near(sphere.closest_point(Vec3::ZERO), Vec3::ZERO, 1e-6);
+
+    // Clockwise triangle
+    let prism = tp_cam::ConfinerShape::ExtrudedPolygon {
+        points: vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 4.0),
+            Vec2::new(4.0, 0.0),
+        ],
+        min_y: 0.0,
+        max_y: 2.0,
+    };
+    assert_vec3_near(
+        prism.closest_point(Vec3::new(1.0, 1.0, 1.0)),
+        Vec3::new(1.0, 1.0, 1.0),
+        1e-6,
+    );
+    assert_vec3_near(
+        prism.closest_point(Vec3::new(-1.0, 5.0, 2.0)),
+        Vec3::new(0.0, 2.0, 2.0),
+        1e-6,
+    );
+    assert_vec3_near(
+        prism.closest_point(Vec3::new(3.0, 1.0, 3.0)),
+        Vec3::new(2.0, 1.0, 2.0),
+        1e-6,
+    );
+}
+
+#[test]
+fn camera_is_confined_at_once() {
+    let mut confiner = tp_cam::CameraConfiner::new(tp_cam::ConfinerShape::Aabb {
+        min: Vec3::splat(-10.0),
+        max: Vec3::splat(10.0),
+    });
+    confiner.smoothing = None;
+    let (mut app, camera) = confined_app(confiner);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 10.0), 1e-4);
+
+    // Slides along the boundary while orbiting
+    app.move_mouse(Vec2::new(-50.0, 0.0)).step(1);
+    let translation = app.camera_transform(camera).translation;
+    assert!((translation.z - 10.0).abs() < 1e-4, "{translation}");
+    assert!(translation.x > 0.0 && translation.x < 10.0, "{translation}");
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<tp_cam::CameraConfiner>();
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-4);
+}
+
+#[test]
+fn camera_is_pushed_back_smoothly() {
+    let (mut app, camera) =
+        confined_app(tp_cam::CameraConfiner::new(tp_cam::ConfinerShape::Sphere {
+            center: TARGET,
+            radius: 10.0,
+        }));
+
+    app.step(2);
+    let pushed = app.camera_transform(camera).translation;
+    assert!(pushed.z > 10.0 && pushed.z < 15.0, "{pushed}");
+
+    app.step(120);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 10.0), 1e-3);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-3);
+}
+
+#[test]
+fn confined_camera_keeps_looking_at_target() {
+    let mut confiner = tp_cam::CameraConfiner::new(tp_cam::ConfinerShape::Aabb {
+        min: Vec3::new(-10.0, -10.0, -10.0),
+        max: Vec3::new(3.0, 10.0, 10.0),
+    });
+    confiner.smoothing = None;
+    let (mut app, camera) = confined_app(confiner);
+
+    app.move_mouse(Vec2::new(-200.0, 0.0)).step(1);
+    let translation = app.camera_transform(camera).translation;
+    assert!((translation.x - 3.0).abs() < 1e-4, "{translation}");
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+}
+
+#[test]
+fn camera_mode_is_confined() {
+    let mut confiner = tp_cam::CameraConfiner::new(tp_cam::ConfinerShape::Aabb {
+        min: Vec3::splat(-10.0),
+        max: Vec3::splat(10.0),
+    });
+    confiner.smoothing = None;
+    let (mut app, camera) = confined_app(confiner);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Fixed {
+            position: Vec3::new(20.0, 5.0, 0.0),
+        },
+        transition: 0.0,
+    });
+    app.step(1);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 5.0, 0.0), 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn local_camera_app() -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture().local().build();
+    (app, camera)
+}
+
+#[test]
+fn mouse_orbits_around_target() {
+    let (mut app, camera) = local_camera_app();
+
+    app.move_mouse(Vec2::new(100.0, 0.0)).step(1);
+
+    let (yaw, _, _) = app
+        .camera_transform(camera)
+        .rotation
+        .to_euler(EulerRot::YXZ);
+    assert!((yaw + 0.5).abs() < 1e-4, "{yaw}");
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+}
+
+#[test]
+fn keyboard_pitch_is_clamped() {
+    let (mut app, camera) = local_camera_app();
+
+    app.press_key(KeyCode::ArrowUp).step(200);
+
+    let (_, pitch, _) = app
+        .camera_transform(camera)
+        .rotation
+        .to_euler(EulerRot::YXZ);
+    assert!((pitch - 89f32.to_radians()).abs() < 1e-3, "{pitch}");
+    assert_camera_looks_at(&app, camera, TARGET, 1e-3);
+}
+
+#[test]
+fn scroll_zooms() {
+    let (mut app, camera) = local_camera_app();
+
+    app.scroll(3.0).step(1);
+
+    assert!((camera_distance(&app, camera) - 12.0).abs() < 1e-4);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 12.0), 1e-4);
+}
+
+#[test]
+fn zoom_is_limited() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture()
+        .settings(tp_cam::ThirdPersonCameraSettings {
+            zoom_limits: Some((5.0, 20.0)),
+            ..default()
+        })
+        .local()
+        .step(0)
+        .build();
+
+    app.scroll(30.0).step(1);
+    assert!((camera_distance(&app, camera) - 5.0).abs() < 1e-4);
+
+    app.scroll(-30.0).step(1);
+    assert!((camera_distance(&app, camera) - 20.0).abs() < 1e-4);
+}
+
+#[test]
+fn zoom_limits_the_distance_with_a_side_offset() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture()
+        .settings(tp_cam::ThirdPersonCameraSettings {
+            zoom_limits: Some((5.0, 20.0)),
+            ..default()
+        })
+        .camera(tp_cam::CameraOffset(Vec3::new(3.0, 0.0, -10.0)))
+        .local()
+        .step(0)
+        .build();
+
+    app.scroll(30.0).step(1);
+    assert!((camera_distance(&app, camera) - 5.0).abs() < 1e-4);
+    let offset = app.world().get::<tp_cam::CameraOffset>(camera).unwrap().0;
+    assert_eq!(offset.x, 3.0);
+
+    app.scroll(-30.0).step(1);
+    assert!((camera_distance(&app, camera) - 20.0).abs() < 1e-4);
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+/// Camera on the first of three targets, `near` and `far` are to its right
+fn spectator_app() -> (App, Entity, [Entity; 3]) {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture()
+        .target(tp_cam::SpectatorTarget { priority: 0 })
+        .step(0)
+        .build();
+    let mut spawn_target = |x: f32, priority: i32| {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(x, 1.0, 0.0),
+                tp_cam::SpectatorTarget { priority },
+            ))
+            .id()
+    };
+    let targets = [target, spawn_target(30.0, 1), spawn_target(10.0, 2)];
+    app.step(1);
+    (app, camera, targets)
+}
+
+fn cycle(app: &mut App, camera: Entity, direction: tp_cam::CycleDirection) -> Entity {
+    app.world_mut()
+        .trigger(tp_cam::CycleTarget { camera, direction });
+    app.step(1);
+    app.world()
+        .get::<tp_cam::ThirdPersonCamera>(camera)
+        .unwrap()
+        .target
+}
+
+#[test]
+fn cycles_by_distance() {
+    let (mut app, camera, [first, far, near]) = spectator_app();
+
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), near);
+    assert!(app.world().entity(camera).contains::<tp_cam::AttachBlend>());
+    app.step(40);
+    assert_camera_looks_at(&app, camera, Vec3::new(10.0, 1.0, 0.0), 1e-4);
+
+    // Still ordered from where the cycling started: first, near, far
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), far);
+    assert_eq!(
+        cycle(&mut app, camera, tp_cam::CycleDirection::Previous),
+        near
+    );
+    assert_eq!(
+        cycle(&mut app, camera, tp_cam::CycleDirection::Previous),
+        first
+    );
+}
+
+#[test]
+fn cycles_through_all_targets_by_distance() {
+    let (mut app, camera, [first, far, near]) = spectator_app();
+
+    let mut visited = Vec::new();
+    for _ in 0..3 {
+        visited.push(cycle(&mut app, camera, tp_cam::CycleDirection::Next));
+        app.step(40);
+    }
+    assert_eq!(visited, [near, far, first]);
+}
+
+#[test]
+fn cycles_after_target_despawned() {
+    let (mut app, camera, [first, _, near]) = spectator_app();
+    app.world_mut().despawn(first);
+    app.step(1);
+    assert!(
+        !app.world()
+            .entity(camera)
+            .contains::<tp_cam::ThirdPersonCamera>()
+    );
+
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), near);
+}
+
+#[test]
+fn cycles_by_priority() {
+    let (mut app, camera, [first, far, near]) = spectator_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::TargetCycling {
+            order: tp_cam::CycleOrder::Priority,
+            ..default()
+        });
+
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), near);
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), far);
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), first);
+}
+
+#[test]
+fn skips_despawned_and_filtered_targets() {
+    let (mut app, camera, [_, far, near]) = spectator_app();
+    app.world_mut().despawn(near);
+
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), far);
+
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::TargetCycling {
+            filter: Some(|target| target.get::<tp_cam::SpectatorTarget>().unwrap().priority > 0),
+            ..default()
+        });
+    // The only eligible target is the current one
+    assert_eq!(cycle(&mut app, camera, tp_cam::CycleDirection::Next), far);
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+#[derive(Resource)]
+struct Received<T>(Vec<T>);
+
+/// Keeps `map` of every `E` triggered from now on
+fn receive<E: EntityEvent, T: Send + Sync + 'static>(app: &mut App, map: fn(&E) -> T) {
+    app.insert_resource(Received::<T>(Vec::new())).add_observer(
+        move |ev: On<E>, mut received: ResMut<Received<T>>| {
+            received.0.push(map(&ev));
+        },
+    );
+}
+
+fn received<T: Clone + Send + Sync + 'static>(app: &App) -> Vec<T> {
+    app.world().resource::<Received<T>>().0.clone()
+}
+
+#[test]
+fn orbited_reports_yaw_and_pitch() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().local().build();
+    receive(&mut app, |ev: &tp_cam::CameraOrbited| {
+        (ev.camera, ev.yaw, ev.pitch)
+    });
+
+    app.move_mouse(Vec2::new(100.0, 0.0)).step(1);
+    let orbited: Vec<(Entity, f32, f32)> = received(&app);
+    assert_eq!(orbited.len(), 1);
+    assert_eq!(orbited[0].0, camera);
+    assert!((orbited[0].1 + 0.5).abs() < 1e-4, "{orbited:?}");
+    assert!(orbited[0].2.abs() < 1e-4, "{orbited:?}");
+}
+
+#[test]
+fn zoomed_reports_distance() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().local().build();
+    receive(&mut app, |ev: &tp_cam::CameraZoomed| {
+        (ev.camera, ev.distance)
+    });
+
+    app.scroll(3.0).step(1);
+    let zoomed: Vec<(Entity, f32)> = received(&app);
+    assert_eq!(zoomed.len(), 1);
+    assert_eq!(zoomed[0].0, camera);
+    assert!((zoomed[0].1 - 12.0).abs() < 1e-4, "{zoomed:?}");
+}
+
+#[test]
+fn rolled_reports_roll() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().build();
+    receive(&mut app, |ev: &tp_cam::CameraRolled| (ev.camera, ev.roll));
+
+    app.world_mut().trigger(tp_cam::Roll {
+        camera,
+        value: 0.25,
+    });
+    app.step(1);
+    let rolled: Vec<(Entity, f32)> = received(&app);
+    assert_eq!(rolled.len(), 1);
+    assert_eq!(rolled[0].0, camera);
+    assert!((rolled[0].1 - 0.25).abs() < 1e-4, "{rolled:?}");
+}
+
+#[test]
+fn target_changed_reports_first_and_new_target() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture()
+        .setup(|app| {
+            receive(app, |ev: &tp_cam::TargetChanged| (ev.camera, ev.target));
+        })
+        .build();
+    let other = app.world_mut().spawn(Transform::default()).id();
+
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::ThirdPersonCamera::aimed_at(other));
+    assert_eq!(
+        received::<(Entity, Entity)>(&app),
+        [(camera, target), (camera, other)]
+    );
+}
+
+#[test]
+fn mode_changed_reports_new_mode() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().build();
+    receive(&mut app, |ev: &tp_cam::CameraModeChanged| {
+        (ev.camera, ev.mode)
+    });
+    let mode = tp_cam::CameraMode::Fixed {
+        position: Vec3::new(10.0, 5.0, 0.0),
+    };
+
+    for _ in 0..2 {
+        app.world_mut().trigger(tp_cam::SetCameraMode {
+            camera,
+            mode,
+            transition: 0.0,
+        });
+        app.step(1);
+    }
+    // Not triggered again for the current mode
+    assert_eq!(
+        received::<(Entity, tp_cam::CameraMode)>(&app),
+        [(camera, mode)]
+    );
+}
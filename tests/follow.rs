@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+#[test]
+fn camera_is_placed_on_spawn() {
+    let CameraFixture { app, camera, .. } = camera_fixture()
+        .camera(tp_cam::InitialRotation {
+            yaw: 90f32.to_radians(),
+            pitch: 0.0,
+        })
+        .build();
+
+    assert_camera_at(&app, camera, Vec3::new(15.0, 1.0, 0.0), 1e-4);
+    assert_camera_looks_at(&app, camera, Vec3::new(0.0, 1.0, 0.0), 1e-4);
+}
+
+#[test]
+fn camera_follows_target() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().build();
+
+    app.move_to(target, Vec3::new(4.0, 1.0, -2.0)).step(2);
+
+    assert_camera_at(&app, camera, Vec3::new(4.0, 1.0, 13.0), 1e-4);
+}
+
+#[test]
+fn damped_camera_lags_then_catches_up() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().camera(tp_cam::DampingFactor(5.0)).build();
+
+    app.move_to(target, Vec3::new(10.0, 1.0, 0.0)).step(3);
+    let lagging = app.camera_transform(camera).translation;
+    assert!(lagging.x > 0.0 && lagging.x < 10.0, "{lagging}");
+
+    app.step(300);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 1.0, 15.0), 1e-3);
+}
+
+#[test]
+fn per_axis_damping_in_world_space() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture()
+        .camera(tp_cam::AxisDamping {
+            factors: Vec3::new(f32::INFINITY, 2.0, f32::INFINITY),
+            space: tp_cam::DampingSpace::World,
+        })
+        .build();
+
+    app.move_to(target, Vec3::new(4.0, 5.0, 0.0)).step(3);
+    let target_point = app.world().get::<tp_cam::TargetPoint>(camera).unwrap().0;
+    assert_eq!(target_point.x, 4.0);
+    assert!(
+        target_point.y > 1.0 && target_point.y < 2.0,
+        "{target_point}"
+    );
+
+    app.step(300);
+    assert_camera_at(&app, camera, Vec3::new(4.0, 5.0, 15.0), 1e-3);
+}
+
+#[test]
+fn per_axis_damping_in_target_space() {
+    // The target's Z axis is the world X axis
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture()
+        .target(
+            Transform::from_xyz(0.0, 1.0, 0.0)
+                .with_rotation(Quat::from_rotation_y(90f32.to_radians())),
+        )
+        .camera(tp_cam::AxisDamping {
+            factors: Vec3::new(f32::INFINITY, f32::INFINITY, 2.0),
+            space: tp_cam::DampingSpace::TargetLocal,
+        })
+        .step(2)
+        .build();
+
+    app.move_to(target, Vec3::new(4.0, 1.0, 4.0)).step(3);
+    let target_point = app.world().get::<tp_cam::TargetPoint>(camera).unwrap().0;
+    assert!((target_point.z - 4.0).abs() < 1e-4, "{target_point}");
+    assert!(
+        target_point.x > 0.0 && target_point.x < 2.0,
+        "{target_point}"
+    );
+}
+
+#[test]
+fn camera_that_is_a_target_follows() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().step(0).build();
+    let chase_camera = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(camera),
+        ))
+        .id();
+    app.step(1);
+
+    assert!(app.world().get::<tp_cam::TargetPoint>(camera).is_some());
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+
+    // One frame later than the camera, once the moved camera is propagated
+    app.move_to(target, Vec3::new(4.0, 1.0, -2.0)).step(3);
+    assert_camera_at(&app, camera, Vec3::new(4.0, 1.0, 13.0), 1e-4);
+    assert_camera_at(&app, chase_camera, Vec3::new(4.0, 1.0, 28.0), 1e-4);
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+fn follow_2d_app(follow_2d: tp_cam::Follow2d, target_position: Vec3) -> (App, Entity, Entity) {
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = camera_fixture()
+        .target(Transform::from_translation(target_position))
+        .camera((
+            Transform::from_xyz(0.0, 0.0, 100.0),
+            Projection::Orthographic(OrthographicProjection {
+                area: Rect::new(-5.0, -3.0, 5.0, 3.0),
+                ..OrthographicProjection::default_2d()
+            }),
+            follow_2d,
+        ))
+        .local()
+        .build();
+    (app, camera, target)
+}
+
+#[derive(Resource, Default)]
+struct Scaled(Vec<f32>);
+
+fn orthographic_scale(app: &App, camera: Entity) -> f32 {
+    match app.world().get::<Projection>(camera) {
+        Some(Projection::Orthographic(orthographic)) => orthographic.scale,
+        _ => panic!("no orthographic projection"),
+    }
+}
+
+#[test]
+fn follows_target_in_xy_plane() {
+    let (mut app, camera, target) =
+        follow_2d_app(tp_cam::Follow2d::default(), Vec3::new(3.0, 4.0, 0.0));
+    assert_camera_at(&app, camera, Vec3::new(3.0, 4.0, 100.0), 1e-4);
+
+    app.move_to(target, Vec3::new(10.0, 2.0, 0.0)).step(2);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 2.0, 100.0), 1e-4);
+
+    app.move_mouse(Vec2::new(100.0, 50.0)).step(1);
+    assert_eq!(app.camera_transform(camera).rotation, Quat::IDENTITY);
+}
+
+#[test]
+fn damping_in_xy_plane() {
+    let (mut app, camera, target) = follow_2d_app(tp_cam::Follow2d::default(), Vec3::ZERO);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::DampingFactor(5.0));
+
+    app.move_to(target, Vec3::new(10.0, 0.0, 0.0)).step(3);
+    let lagging = app.camera_transform(camera).translation;
+    assert!(lagging.x > 0.0 && lagging.x < 10.0, "{lagging}");
+    assert_eq!(lagging.z, 100.0);
+
+    app.step(300);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 0.0, 100.0), 1e-2);
+}
+
+#[test]
+fn zoom_scales_projection() {
+    let (mut app, camera, _) = follow_2d_app(
+        tp_cam::Follow2d {
+            scale_limits: Some((0.5, 2.0)),
+            ..default()
+        },
+        Vec3::ZERO,
+    );
+
+    app.init_resource::<Scaled>().add_observer(
+        |scaled_ev: On<tp_cam::CameraScaled>, mut scaled: ResMut<Scaled>| {
+            scaled.0.push(scaled_ev.scale);
+        },
+    );
+    app.scroll(3.0).step(1);
+    assert!((orthographic_scale(&app, camera) - (-0.3f32).exp()).abs() < 1e-4);
+    assert_eq!(
+        app.world().resource::<Scaled>().0,
+        [orthographic_scale(&app, camera)]
+    );
+    assert_camera_at(&app, camera, Vec3::new(0.0, 0.0, 100.0), 1e-4);
+
+    app.scroll(-30.0).step(1);
+    assert!((orthographic_scale(&app, camera) - 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn view_stays_inside_bounds() {
+    let (mut app, camera, target) = follow_2d_app(
+        tp_cam::Follow2d {
+            bounds: Some(Rect::new(-20.0, -10.0, 20.0, 10.0)),
+            ..default()
+        },
+        Vec3::new(18.0, -9.0, 0.0),
+    );
+    assert_camera_at(&app, camera, Vec3::new(15.0, -7.0, 100.0), 1e-4);
+
+    app.move_to(target, Vec3::new(0.0, 2.0, 0.0)).step(2);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 2.0, 100.0), 1e-4);
+
+    // Wider than the bounds
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(Projection::Orthographic(OrthographicProjection {
+            area: Rect::new(-25.0, -3.0, 25.0, 3.0),
+            ..OrthographicProjection::default_2d()
+        }));
+    app.step(1);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 2.0, 100.0), 1e-4);
+    app.move_to(target, Vec3::new(10.0, 2.0, 0.0)).step(2);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 2.0, 100.0), 1e-4);
+}
+
+#[test]
+fn zoomed_view_stays_inside_bounds() {
+    let (mut app, camera, _) = follow_2d_app(
+        tp_cam::Follow2d {
+            bounds: Some(Rect::new(-20.0, -10.0, 20.0, 10.0)),
+            ..default()
+        },
+        Vec3::new(18.0, -9.0, 0.0),
+    );
+    assert_camera_at(&app, camera, Vec3::new(15.0, -7.0, 100.0), 1e-4);
+
+    // Twice the view size, clamped on the frame of the zoom
+    app.scroll(-10.0 * 2f32.ln()).step(1);
+    assert!((orthographic_scale(&app, camera) - 2.0).abs() < 1e-4);
+    assert_camera_at(&app, camera, Vec3::new(10.0, -4.0, 100.0), 1e-3);
+}
+
+#[test]
+fn framing_zones_in_xy_plane() {
+    let (mut app, camera, target) = follow_2d_app(tp_cam::Follow2d::default(), Vec3::ZERO);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::FramingZones {
+            space: tp_cam::FramingSpace::World,
+            dead_zone: Vec3::new(1.0, 1.0, 0.0),
+            soft_zone: Vec3::new(2.0, 2.0, 0.0),
+            damping: 5.0,
+        });
+
+    app.move_to(target, Vec3::new(0.8, -0.8, 0.0)).step(10);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 0.0, 100.0), 1e-6);
+
+    app.move_to(target, Vec3::new(4.0, 0.0, 0.0)).step(2);
+    let lagging = app.camera_transform(camera).translation;
+    assert!(lagging.x >= 2.0 && lagging.x < 3.0, "{lagging}");
+
+    app.step(300);
+    assert_camera_at(&app, camera, Vec3::new(3.0, 0.0, 100.0), 1e-3);
+}
@@ -0,0 +1,69 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn framing_app(framing: tp_cam::FramingZones, yaw: f32) -> (App, Entity, Entity) {
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = camera_fixture()
+        .camera((tp_cam::InitialRotation { yaw, pitch: 0.0 }, framing))
+        .step(2)
+        .build();
+    (app, camera, target)
+}
+
+fn target_point(app: &App, camera: Entity) -> Vec3 {
+    app.world().get::<tp_cam::TargetPoint>(camera).unwrap().0
+}
+
+/// Dead zone of 1 and soft zone of 2 along X only
+fn x_zones(space: tp_cam::FramingSpace) -> tp_cam::FramingZones {
+    tp_cam::FramingZones {
+        space,
+        dead_zone: Vec3::new(1.0, 0.0, 0.0),
+        soft_zone: Vec3::new(2.0, 0.0, 0.0),
+        damping: 5.0,
+    }
+}
+
+#[test]
+fn dead_zone_keeps_camera_still() {
+    let (mut app, camera, target) = framing_app(x_zones(tp_cam::FramingSpace::World), 0.0);
+    let camera_at = app.camera_transform(camera).translation;
+
+    app.move_to(target, TARGET + Vec3::X * 0.8).step(10);
+
+    assert_eq!(target_point(&app, camera), TARGET);
+    assert_camera_at(&app, camera, camera_at, 1e-6);
+}
+
+#[test]
+fn soft_zone_catches_up_to_dead_zone() {
+    let (mut app, camera, target) = framing_app(x_zones(tp_cam::FramingSpace::World), 0.0);
+
+    app.move_to(target, TARGET + Vec3::X * 4.0).step(2);
+    // Never further than the soft zone
+    let lag = TARGET.x + 4.0 - target_point(&app, camera).x;
+    assert!(lag <= 2.0 + 1e-4 && lag > 1.0);
+
+    app.step(120);
+    assert_vec3_near(target_point(&app, camera), TARGET + Vec3::X * 3.0, 1e-3);
+    assert_camera_looks_at(&app, camera, target_point(&app, camera), 1e-4);
+}
+
+#[test]
+fn screen_space_zones_follow_camera_axes() {
+    // Looking along -X, the screen X axis is the world -Z axis
+    let (mut app, camera, target) = framing_app(x_zones(tp_cam::FramingSpace::Screen), FRAC_PI_2);
+
+    app.move_to(target, TARGET + Vec3::Z * 0.8).step(5);
+    assert_vec3_near(target_point(&app, camera), TARGET, 1e-5);
+
+    app.move_to(target, TARGET + Vec3::X * 0.8).step(5);
+    assert_vec3_near(target_point(&app, camera), TARGET + Vec3::X * 0.8, 1e-5);
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+fn detached_camera_app() -> (App, Entity, Entity) {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().local().build();
+    app.world_mut().trigger(tp_cam::DetachCamera { camera });
+    (app, camera, target)
+}
+
+#[test]
+fn detached_camera_flies_freely() {
+    let (mut app, camera, target) = detached_camera_app();
+
+    app.press_key(KeyCode::KeyW).step(10);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 13.4), 1e-3);
+
+    app.release_key(KeyCode::KeyW)
+        .move_to(target, Vec3::new(5.0, 1.0, 0.0))
+        .scroll(3.0)
+        .step(5);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 13.4), 1e-3);
+}
+
+#[test]
+fn attach_blends_to_new_target() {
+    let (mut app, camera, _) = detached_camera_app();
+    let other_target = app
+        .world_mut()
+        .spawn(Transform::from_xyz(10.0, 1.0, 0.0))
+        .id();
+    app.press_key(KeyCode::KeyD)
+        .step(10)
+        .release_key(KeyCode::KeyD);
+    let detached_at = app.camera_transform(camera).translation;
+
+    app.world_mut().trigger(tp_cam::AttachCamera {
+        camera,
+        target: other_target,
+        blend_duration: 0.32,
+    });
+    app.step(10);
+    let blending_at = app.camera_transform(camera).translation;
+    assert!(blending_at.x > detached_at.x && blending_at.x < 10.0);
+
+    app.step(15);
+    assert!(!app.world().entity(camera).contains::<tp_cam::AttachBlend>());
+    assert_camera_at(&app, camera, Vec3::new(10.0, 1.0, 15.0), 1e-3);
+    assert_camera_looks_at(&app, camera, Vec3::new(10.0, 1.0, 0.0), 1e-4);
+}
+
+#[test]
+fn reattach_to_same_target() {
+    let (mut app, camera, target) = detached_camera_app();
+    app.press_key(KeyCode::Space)
+        .step(10)
+        .release_key(KeyCode::Space);
+
+    app.world_mut().trigger(tp_cam::AttachCamera {
+        camera,
+        target,
+        blend_duration: 0.16,
+    });
+    app.step(15);
+
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-3);
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-4);
+}
+
+#[test]
+fn attach_after_target_despawned() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().build();
+    let other_target = app
+        .world_mut()
+        .spawn(Transform::from_xyz(10.0, 1.0, 0.0))
+        .id();
+    app.world_mut().despawn(target);
+    app.step(2);
+    assert!(
+        !app.world()
+            .entity(camera)
+            .contains::<tp_cam::ThirdPersonCamera>()
+    );
+
+    app.world_mut().trigger(tp_cam::AttachCamera {
+        camera,
+        target: other_target,
+        blend_duration: 0.0,
+    });
+    app.step(2);
+
+    let tp_cam = app
+        .world()
+        .get::<tp_cam::ThirdPersonCamera>(camera)
+        .unwrap();
+    assert_eq!(tp_cam.target, other_target);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 1.0, 15.0), 1e-3);
+}
@@ -0,0 +1,204 @@
+use bevy::{
+    gizmos::{GizmoAsset, GizmoHandles},
+    prelude::*,
+};
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+use std::any::TypeId;
+
+/// Line vertices drawn during the last update
+fn drawn_lines(app: &App) -> Vec<Vec3> {
+    let Some(Some(handle)) = app
+        .world()
+        .resource::<GizmoHandles>()
+        .handles()
+        .get(&TypeId::of::<DefaultGizmoConfigGroup>())
+    else {
+        return Vec::new();
+    };
+    let buffer = app
+        .world()
+        .resource::<Assets<GizmoAsset>>()
+        .get(handle)
+        .unwrap()
+        .buffer();
+    buffer
+        .list_positions
+        .iter()
+        .chain(&buffer.strip_positions)
+        .copied()
+        .collect()
+}
+
+fn gizmo_app(config: tp_cam::CameraGizmosConfig) -> App {
+    let CameraFixture { mut app, .. } = camera_fixture()
+        .settings(tp_cam::ThirdPersonCameraSettings {
+            show_relation_gizmo: true,
+            zoom_limits: Some((5.0, 20.0)),
+            ..default()
+        })
+        .setup(move |app| {
+            app.insert_resource(config);
+        })
+        .camera(tp_cam::CameraGizmoColor(Color::BLACK))
+        .step(3)
+        .build();
+    app.step(1);
+    app
+}
+
+#[test]
+fn relation_line_is_drawn() {
+    let app = gizmo_app(default());
+
+    let lines = drawn_lines(&app);
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(lines.contains(&Vec3::new(0.0, 1.0, 0.0)), "{lines:?}");
+    assert!(lines.contains(&Vec3::new(0.0, 1.0, 15.0)), "{lines:?}");
+}
+
+#[test]
+fn relation_lines_of_chained_cameras_are_drawn() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture()
+        .settings(tp_cam::ThirdPersonCameraSettings {
+            show_relation_gizmo: true,
+            ..default()
+        })
+        .step(0)
+        .build();
+    let chase_camera = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(camera),
+        ))
+        .id();
+    app.step(4);
+
+    let lines = drawn_lines(&app);
+    assert_eq!(lines.len(), 4, "{lines:?}");
+    for entity in [camera, chase_camera] {
+        let translation = app
+            .world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation();
+        assert!(lines.contains(&translation), "{translation} {lines:?}");
+    }
+}
+
+#[test]
+fn all_gizmos_are_drawn() {
+    let app = gizmo_app(tp_cam::CameraGizmosConfig {
+        relation_line: true,
+        orbit_sphere: true,
+        pitch_limits: true,
+        target_points: true,
+        target_offset: true,
+        zoom_range: true,
+    });
+
+    let lines = drawn_lines(&app);
+    assert!(lines.len() > 100, "{}", lines.len());
+    // The zoom range segment along the camera direction
+    for zoom in [5.0, 20.0] {
+        let end = Vec3::new(0.0, 1.0, zoom);
+        assert!(
+            lines.iter().any(|point| point.distance(end) < 1e-4),
+            "{end}"
+        );
+    }
+}
+
+#[test]
+fn no_gizmos_while_hidden() {
+    let CameraFixture { mut app, .. } = camera_fixture().step(3).build();
+    app.step(1);
+
+    assert!(drawn_lines(&app).is_empty());
+}
+
+#[cfg(feature = "debug_overlay")]
+#[test]
+fn debug_overlay_is_toggled() {
+    let CameraFixture { mut app, .. } = camera_fixture().local().step(0).build();
+
+    app.press_key(KeyCode::F3).step(2);
+
+    assert!(app.world().resource::<tp_cam::DebugOverlayConfig>().visible);
+}
+
+#[cfg(feature = "debug_overlay")]
+#[test]
+fn debug_overlay_draws_in_front_of_the_camera() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().local().build();
+    assert!(drawn_lines(&app).is_empty());
+
+    app.press_key(KeyCode::F3).step(2);
+
+    let lines = drawn_lines(&app);
+    assert!(!lines.is_empty());
+    let camera_transform = *app.world().get::<Transform>(camera).unwrap();
+    let corner = camera_transform
+        .transform_point(app.world().resource::<tp_cam::DebugOverlayConfig>().offset);
+    // Text lines run right and down from the top left corner, on the plane facing the camera.
+    // NaN separates the line strips.
+    for point in lines.into_iter().filter(|point| point.is_finite()) {
+        let local = camera_transform.rotation.inverse() * (point - corner);
+        assert!(local.z.abs() < 1e-4, "{local}");
+        assert!(local.x > -1e-4 && local.y < 1e-4, "{local}");
+    }
+}
+
+#[cfg(feature = "debug_overlay")]
+#[test]
+fn debug_overlay_reports_input_source() {
+    let overlay = |insert: fn(&mut EntityWorldMut)| {
+        let CameraFixture {
+            mut app, camera, ..
+        } = camera_fixture().local().build();
+        app.world_mut()
+            .resource_mut::<tp_cam::DebugOverlayConfig>()
+            .visible = true;
+        insert(&mut app.world_mut().entity_mut(camera));
+        app.step(1);
+        drawn_lines(&app)
+    };
+
+    let readouts = [
+        overlay(|_| {}),
+        overlay(|camera| {
+            camera.insert(tp_cam::InputReplay::new(tp_cam::CameraInputLog {
+                events: vec![tp_cam::RecordedEvent {
+                    time: 10.0,
+                    input: tp_cam::RecordedInput::Zoom(1.0),
+                }],
+            }));
+        }),
+        overlay(|camera| {
+            camera.insert(tp_cam::SnapshotBuffer::default());
+        }),
+        overlay(|camera| {
+            let id = camera.id();
+            camera.world_scope(|world| {
+                world.trigger(tp_cam::PlaySequence {
+                    camera: id,
+                    sequence: tp_cam::CameraSequence {
+                        keyframes: Vec::new(),
+                        return_duration: 10.0,
+                        return_easing: None,
+                    },
+                })
+            });
+        }),
+    ];
+    for (i, readout) in readouts.iter().enumerate() {
+        assert!(!readout.is_empty());
+        for other in &readouts[i + 1..] {
+            assert_ne!(readout, other);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+/// Camera at pitch 0, 15 units behind the target
+fn ground_app(ground: tp_cam::GroundHeightQuery) -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture()
+        .setup(|app| {
+            app.insert_resource(ground);
+        })
+        .camera(tp_cam::GroundClearance::default())
+        .build();
+    (app, camera)
+}
+
+fn hill(position: Vec2) -> Option<f32> {
+    Some(if position.y > 10.0 { 3.0 } else { 0.0 })
+}
+
+#[test]
+fn camera_stays_above_ground() {
+    let (mut app, camera) = ground_app(tp_cam::GroundHeightQuery::new(hill));
+    app.step(1);
+
+    let camera_transform = app.camera_transform(camera);
+    assert!(camera_transform.translation.y >= 3.5 - 1e-3);
+    assert!(camera_transform.translation.y < 4.5);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-3);
+}
+
+#[test]
+fn player_pitch_restored_when_clear() {
+    let (mut app, camera) = ground_app(tp_cam::GroundHeightQuery::new(hill));
+    app.step(1);
+    assert!(app.camera_transform(camera).translation.y > 3.0);
+
+    app.insert_resource(tp_cam::GroundHeightQuery::new(|_| Some(0.0)));
+    app.step(2);
+    let restoring_y = app.camera_transform(camera).translation.y;
+    assert!(restoring_y > 1.0 && restoring_y < 3.5);
+
+    app.step(20);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn no_ground_keeps_player_pitch() {
+    let (mut app, camera) = ground_app(tp_cam::GroundHeightQuery::new(|_| None));
+    app.step(1);
+
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn ground_clearance_applies_after_volumes() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture()
+        .setup(|app| {
+            app.insert_resource(tp_cam::GroundHeightQuery::new(|_| Some(0.0)));
+            // Looking up puts the camera below the target
+            app.world_mut().spawn((
+                Transform::default(),
+                tp_cam::CameraVolume::new(
+                    tp_cam::VolumeShape::Aabb {
+                        half_size: Vec3::splat(5.0),
+                    },
+                    tp_cam::CameraOverrides {
+                        pitch: Some(0.3),
+                        ..default()
+                    },
+                ),
+            ));
+        })
+        .camera(tp_cam::GroundClearance::default())
+        .build();
+    app.step(60);
+
+    let camera_transform = app.camera_transform(camera);
+    assert!(
+        camera_transform.translation.y >= 0.5 - 1e-3,
+        "{}",
+        camera_transform.translation
+    );
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-3);
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn look_ahead_app(look_ahead: tp_cam::LookAhead) -> (App, Entity, Entity) {
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = camera_fixture().camera(look_ahead).step(2).build();
+    (app, camera, target)
+}
+
+fn target_point(app: &App, camera: Entity) -> Vec3 {
+    app.world().get::<tp_cam::TargetPoint>(camera).unwrap().0
+}
+
+#[test]
+fn target_point_leads_moving_target() {
+    let (mut app, camera, target) = look_ahead_app(tp_cam::LookAhead::default());
+
+    // 2 units per second along X
+    let mut position = TARGET;
+    for _ in 0..120 {
+        position.x += 2.0 * FRAME_TIME.as_secs_f32();
+        app.move_to(target, position).step(1);
+    }
+
+    // The target point follows the propagated position of the previous frame
+    let lead = target_point(&app, camera) - (position - Vec3::X * 2.0 * FRAME_TIME.as_secs_f32());
+    assert_vec3_near(lead, Vec3::new(1.0, 0.0, 0.0), 1e-2);
+    assert_camera_looks_at(&app, camera, target_point(&app, camera), 1e-4);
+}
+
+#[test]
+fn look_ahead_is_limited_and_settles() {
+    let (mut app, camera, target) = look_ahead_app(tp_cam::LookAhead::new(0.5, 0.5));
+
+    let mut position = TARGET;
+    for _ in 0..120 {
+        position.z -= 20.0 * FRAME_TIME.as_secs_f32();
+        app.move_to(target, position).step(1);
+    }
+    let previous = position + Vec3::Z * 20.0 * FRAME_TIME.as_secs_f32();
+    assert_vec3_near(
+        target_point(&app, camera) - previous,
+        Vec3::new(0.0, 0.0, -0.5),
+        1e-2,
+    );
+
+    app.step(240);
+    assert_eq!(target_point(&app, camera), position);
+}
+
+#[test]
+fn provided_velocity_is_used() {
+    let mut look_ahead = tp_cam::LookAhead::default();
+    look_ahead.smoothing = 100.0;
+    let (mut app, camera, target) = look_ahead_app(look_ahead);
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::new(0.0, 0.0, 4.0)));
+    app.step(1);
+
+    assert_vec3_near(target_point(&app, camera), TARGET + Vec3::Z * 2.0, 1e-4);
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+const ORBIT: Vec3 = Vec3::new(0.0, 1.0, 15.0);
+
+fn mode_app() -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture().step(2).build();
+    (app, camera)
+}
+
+#[test]
+fn fixed_mode_looks_at_target() {
+    let (mut app, camera) = mode_app();
+    let position = Vec3::new(10.0, 5.0, 0.0);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Fixed { position },
+        transition: 0.0,
+    });
+    app.step(1);
+    assert_camera_at(&app, camera, position, 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<tp_cam::CameraMode>();
+    assert_camera_at(&app, camera, ORBIT, 1e-4);
+}
+
+#[test]
+fn look_at_point_keeps_orbit_position() {
+    let (mut app, camera) = mode_app();
+    let point = Vec3::new(5.0, 0.0, 0.0);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::LookAtPoint { point },
+        transition: 0.0,
+    });
+    app.step(1);
+    assert_camera_at(&app, camera, ORBIT, 1e-4);
+    assert_camera_looks_at(&app, camera, point, 1e-4);
+}
+
+#[test]
+fn mode_transitions_back_to_orbit() {
+    let (mut app, camera) = mode_app();
+    let position = Vec3::new(10.0, 5.0, 0.0);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Fixed { position },
+        transition: 0.0,
+    });
+    app.step(1);
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Orbit,
+        transition: 1.0,
+    });
+
+    app.step(30);
+    let blending = app.camera_transform(camera).translation;
+    assert!(blending.distance(position) > 1e-2, "{blending}");
+    assert!(blending.distance(ORBIT) > 1e-2, "{blending}");
+
+    app.step(60);
+    assert_camera_at(&app, camera, ORBIT, 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+}
+
+#[test]
+fn end_of_transition_keeps_the_mode_pose() {
+    let (mut app, camera) = mode_app();
+    let position = Vec3::new(10.0, 5.0, 0.0);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Fixed { position },
+        transition: 0.2,
+    });
+    app.step(1);
+    while app.world().entity(camera).contains::<tp_cam::AttachBlend>() {
+        app.step(1);
+    }
+
+    // Rendered on the frame the blend ended
+    let rendered = app.world().get::<GlobalTransform>(camera).unwrap();
+    assert!(
+        rendered.translation().distance(position) < 1e-4,
+        "{}",
+        rendered.translation()
+    );
+}
+
+#[test]
+fn mode_changes_after_target_despawned() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().step(2).build();
+    app.world_mut().despawn(target);
+    app.step(1);
+    let position = Vec3::new(10.0, 5.0, 0.0);
+
+    app.world_mut().trigger(tp_cam::SetCameraMode {
+        camera,
+        mode: tp_cam::CameraMode::Fixed { position },
+        transition: 0.0,
+    });
+    app.step(1);
+    assert_camera_at(&app, camera, position, 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+}
@@ -0,0 +1,217 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+/// Local camera and a remote one fed with its encoded snapshots
+fn loopback_app() -> (App, Entity, Entity) {
+    let CameraFixture {
+        mut app,
+        camera: local,
+        target,
+    } = camera_fixture()
+        .target(tp_cam::NetworkTargetId(1))
+        .local()
+        .step(0)
+        .build();
+    let remote = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(target),
+            tp_cam::SnapshotBuffer::default(),
+        ))
+        .id();
+    app.step(1);
+    (app, local, remote)
+}
+
+/// Snapshot of `camera` with the time it's sent at
+fn snapshot_of(app: &App, camera: Entity) -> (f32, tp_cam::CameraSnapshot) {
+    let world = app.world();
+    let target = world
+        .get::<tp_cam::ThirdPersonCamera>(camera)
+        .unwrap()
+        .target;
+    let snapshot = tp_cam::CameraSnapshot::from_camera(
+        world.get::<Transform>(camera).unwrap(),
+        world.get::<tp_cam::CameraOffset>(camera).unwrap(),
+        *world.get::<tp_cam::NetworkTargetId>(target).unwrap(),
+    );
+    (world.resource::<Time>().elapsed_secs(), snapshot)
+}
+
+/// Encodes a snapshot sent at the given time and pushes it to the buffer of `remote` in `to`
+fn send_snapshot((sent_at, snapshot): (f32, tp_cam::CameraSnapshot), to: &mut App, remote: Entity) {
+    let bytes = snapshot.encode();
+    to.world_mut()
+        .get_mut::<tp_cam::SnapshotBuffer>(remote)
+        .unwrap()
+        .push(sent_at, tp_cam::CameraSnapshot::decode(&bytes).unwrap());
+}
+
+#[test]
+fn snapshot_round_trip_is_precise() {
+    let snapshot = tp_cam::CameraSnapshot {
+        yaw: 2.5,
+        pitch: -0.7,
+        roll: -3.0,
+        distance: 12.34,
+        target: 42,
+    };
+    let bytes = snapshot.encode();
+    let decoded = tp_cam::CameraSnapshot::decode(&bytes).unwrap();
+
+    assert_eq!(bytes.len(), tp_cam::CameraSnapshot::ENCODED_LEN);
+    assert!((decoded.yaw - snapshot.yaw).abs() < 1e-4);
+    assert!((decoded.pitch - snapshot.pitch).abs() < 1e-4);
+    assert!((decoded.roll - snapshot.roll).abs() < 1e-4);
+    assert!((decoded.distance - snapshot.distance).abs() < 0.01);
+    assert_eq!(decoded.target, snapshot.target);
+    assert_eq!(tp_cam::CameraSnapshot::decode(&bytes[1..]), None);
+}
+
+#[test]
+fn buffer_interpolates_along_shortest_arc() {
+    let snapshot = |yaw| tp_cam::CameraSnapshot {
+        yaw,
+        pitch: 0.0,
+        roll: 0.0,
+        distance: 10.0,
+        target: 1,
+    };
+    let mut buffer = tp_cam::SnapshotBuffer::default();
+    buffer.push(1.0, snapshot(PI - 0.1));
+    buffer.push(2.0, snapshot(-PI + 0.1));
+    buffer.push(1.5, snapshot(0.0));
+
+    let middle = buffer.sample(1.5).unwrap();
+    assert!((middle.yaw.abs() - PI).abs() < 1e-5);
+    assert_eq!(buffer.sample(3.0).unwrap().yaw, -PI + 0.1);
+    assert_eq!(buffer.snapshots.len(), 1);
+}
+
+#[test]
+fn remote_camera_follows_local_camera() {
+    let (mut app, local, remote) = loopback_app();
+
+    for _ in 0..30 {
+        app.move_mouse(Vec2::new(6.0, 2.0));
+        send_snapshot(snapshot_of(&app, local), &mut app, remote);
+        app.step(1);
+    }
+    app.scroll(2.0);
+    for _ in 0..30 {
+        send_snapshot(snapshot_of(&app, local), &mut app, remote);
+        app.step(1);
+    }
+
+    let local_transform = app.camera_transform(local);
+    let remote_transform = app.camera_transform(remote);
+    assert_camera_at(&app, remote, local_transform.translation, 1e-2);
+    assert!(
+        local_transform
+            .rotation
+            .angle_between(remote_transform.rotation)
+            < 1e-3
+    );
+    assert!((camera_distance(&app, remote) - camera_distance(&app, local)).abs() < 1e-2);
+}
+
+#[test]
+fn remote_camera_switches_target() {
+    let (mut app, local, remote) = loopback_app();
+    let other_target = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(20.0, 0.0, 0.0),
+            tp_cam::NetworkTargetId(2),
+        ))
+        .id();
+    app.step(1);
+
+    app.world_mut()
+        .entity_mut(local)
+        .insert(tp_cam::ThirdPersonCamera::aimed_at(other_target));
+    for _ in 0..20 {
+        send_snapshot(snapshot_of(&app, local), &mut app, remote);
+        app.step(1);
+    }
+
+    let remote_cam = app
+        .world()
+        .get::<tp_cam::ThirdPersonCamera>(remote)
+        .unwrap();
+    assert_eq!(remote_cam.target, other_target);
+    assert_camera_at(&app, remote, app.camera_transform(local).translation, 1e-2);
+}
+
+#[test]
+fn remote_camera_switches_target_in_another_world() {
+    let CameraFixture {
+        app: mut sender,
+        camera: local,
+        ..
+    } = camera_fixture()
+        .target(tp_cam::NetworkTargetId(1))
+        .local()
+        .step(0)
+        .build();
+    let CameraFixture {
+        app: mut receiver,
+        camera: remote,
+        ..
+    } = camera_fixture()
+        .target(tp_cam::NetworkTargetId(1))
+        .camera(tp_cam::SnapshotBuffer::default())
+        .step(0)
+        .build();
+
+    let sender_target = sender
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(20.0, 0.0, 0.0),
+            tp_cam::NetworkTargetId(2),
+        ))
+        .id();
+    // Shifts the entities of the receiver
+    receiver.world_mut().spawn_empty();
+    let receiver_target = receiver
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(20.0, 0.0, 0.0),
+            tp_cam::NetworkTargetId(2),
+        ))
+        .id();
+    assert_ne!(sender_target, receiver_target);
+    sender.step(1);
+    receiver.step(1);
+
+    sender
+        .world_mut()
+        .entity_mut(local)
+        .insert(tp_cam::ThirdPersonCamera::aimed_at(sender_target));
+    for _ in 0..20 {
+        sender.move_mouse(Vec2::new(4.0, 1.0));
+        send_snapshot(snapshot_of(&sender, local), &mut receiver, remote);
+        sender.step(1);
+        receiver.step(1);
+    }
+    for _ in 0..20 {
+        send_snapshot(snapshot_of(&sender, local), &mut receiver, remote);
+        sender.step(1);
+        receiver.step(1);
+    }
+
+    let remote_cam = receiver
+        .world()
+        .get::<tp_cam::ThirdPersonCamera>(remote)
+        .unwrap();
+    assert_eq!(remote_cam.target, receiver_target);
+    assert_camera_at(
+        &receiver,
+        remote,
+        sender.camera_transform(local).translation,
+        1e-2,
+    );
+}
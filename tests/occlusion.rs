@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+#[derive(Component)]
+struct FadedAlpha(f32);
+
+#[derive(Resource, Default)]
+struct Collisions(Vec<(Entity, Entity)>);
+
+/// Camera at (0, 1, 15) aimed at a target at (0, 1, 0) and a pillar in between
+fn occlusion_app() -> (App, Entity, Entity) {
+    let CameraFixture {
+        mut app, target, ..
+    } = camera_fixture()
+        .setup(|app| {
+            app.init_resource::<Collisions>().add_observer(
+                |collided_ev: On<tp_cam::CameraCollided>, mut collisions: ResMut<Collisions>| {
+                    collisions
+                        .0
+                        .push((collided_ev.camera, collided_ev.occluder));
+                },
+            );
+            app.insert_resource(tp_cam::OccluderFade {
+                on_fade: Some(|occluder, alpha| {
+                    occluder.insert(FadedAlpha(alpha));
+                }),
+                ..default()
+            });
+        })
+        .target(tp_cam::Occluder(tp_cam::OccluderShape::Sphere {
+            radius: 0.5,
+        }))
+        .step(0)
+        .build();
+    let pillar = app
+        .world_mut()
+        .spawn((
+            Transform::from_xyz(0.5, 2.0, 7.0),
+            tp_cam::Occluder(tp_cam::OccluderShape::Aabb {
+                half_size: Vec3::new(1.0, 2.0, 1.0),
+            }),
+        ))
+        .id();
+    app.step(1);
+    (app, target, pillar)
+}
+
+fn occluding(app: &App, entity: Entity) -> Option<tp_cam::OccludingTarget> {
+    app.world().get::<tp_cam::OccludingTarget>(entity).copied()
+}
+
+#[test]
+fn occluder_fades_out() {
+    let (mut app, target, pillar) = occlusion_app();
+
+    assert!(occluding(&app, pillar).is_some_and(|o| o.occluding));
+    assert_eq!(occluding(&app, target), None);
+
+    app.step(30);
+    assert_eq!(occluding(&app, pillar).unwrap().alpha, 0.3);
+    assert_eq!(app.world().get::<FadedAlpha>(pillar).unwrap().0, 0.3);
+}
+
+#[test]
+fn occluder_fades_back_in_when_clear() {
+    let (mut app, _, pillar) = occlusion_app();
+    app.step(30);
+
+    app.move_to(pillar, Vec3::new(5.0, 2.0, 7.0)).step(1);
+    let fading_in = occluding(&app, pillar).unwrap();
+    assert!(!fading_in.occluding && fading_in.alpha > 0.3);
+
+    app.step(30);
+    assert_eq!(occluding(&app, pillar), None);
+    assert_eq!(app.world().get::<FadedAlpha>(pillar).unwrap().0, 1.0);
+}
+
+#[test]
+fn rotated_occluder_is_detected() {
+    let (mut app, _, pillar) = occlusion_app();
+    // Thin wall along the view, beside it
+    app.world_mut().entity_mut(pillar).insert((
+        Transform::from_xyz(2.0, 1.0, 7.0),
+        tp_cam::Occluder(tp_cam::OccluderShape::Aabb {
+            half_size: Vec3::new(0.1, 1.0, 3.0),
+        }),
+    ));
+    app.step(30);
+    assert_eq!(occluding(&app, pillar), None);
+
+    // Across the view once rotated
+    app.world_mut()
+        .get_mut::<Transform>(pillar)
+        .unwrap()
+        .rotate_y(std::f32::consts::FRAC_PI_2);
+    app.step(1);
+    assert!(occluding(&app, pillar).is_some_and(|o| o.occluding));
+}
+
+fn collided_occluders(app: &App) -> Vec<Entity> {
+    let collisions = &app.world().resource::<Collisions>().0;
+    collisions.iter().map(|(_, occluder)| *occluder).collect()
+}
+
+#[test]
+fn collision_is_triggered_once() {
+    let (mut app, _, pillar) = occlusion_app();
+    app.step(30);
+    assert_eq!(collided_occluders(&app), [pillar]);
+
+    app.move_to(pillar, Vec3::new(5.0, 2.0, 7.0)).step(60);
+    app.move_to(pillar, Vec3::new(0.5, 2.0, 7.0)).step(1);
+    assert_eq!(collided_occluders(&app), [pillar, pillar]);
+}
+
+#[test]
+fn collision_is_triggered_per_camera() {
+    let (mut app, target, pillar) = occlusion_app();
+    app.step(1);
+    let second = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(target),
+        ))
+        .id();
+    app.step(2);
+    let collisions = app.world().resource::<Collisions>().0.clone();
+    assert_eq!(collisions.len(), 2);
+    assert_eq!(collisions[1], (second, pillar));
+
+    // Clear and blocked again while the first camera keeps the pillar occluding
+    app.world_mut().trigger(tp_cam::RotateAroundTarget {
+        camera: second,
+        delta: Vec2::new(100.0, 0.0),
+    });
+    app.step(1);
+    app.world_mut().trigger(tp_cam::RotateAroundTarget {
+        camera: second,
+        delta: Vec2::new(-100.0, 0.0),
+    });
+    app.step(1);
+    assert!(occluding(&app, pillar).unwrap().occluding);
+    assert_eq!(
+        &app.world().resource::<Collisions>().0[2..],
+        [(second, pillar)]
+    );
+}
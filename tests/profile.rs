@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+/// Loads `assets/camera.tpcam.ron` with the asset server
+fn load_profile(app: &mut App) -> Handle<tp_cam::ThirdPersonCameraProfile> {
+    let handle = app
+        .world()
+        .resource::<AssetServer>()
+        .load("camera.tpcam.ron");
+    for _ in 0..500 {
+        if app
+            .world()
+            .resource::<AssetServer>()
+            .is_loaded_with_dependencies(&handle)
+        {
+            return handle;
+        }
+        app.step(1);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+    }
+    panic!("profile not loaded: {:?}", handle.path());
+}
+
+#[test]
+fn profile_file_is_loaded() {
+    let CameraFixture { mut app, .. } = camera_fixture().build();
+    let handle = load_profile(&mut app);
+
+    let profile = app
+        .world()
+        .resource::<Assets<tp_cam::ThirdPersonCameraProfile>>()
+        .get(&handle)
+        .unwrap();
+    assert_eq!(profile.camera_offset, Some(Vec3::new(0.0, -2.0, -12.0)));
+    assert_eq!(profile.damping, Some(5.0));
+    assert_eq!(profile.roll_clockwise, Some(KeyCode::KeyR));
+    assert_eq!(profile.up, None);
+}
+
+#[test]
+fn settings_profile_is_applied() {
+    let CameraFixture { mut app, .. } = camera_fixture().build();
+    let handle = load_profile(&mut app);
+    app.insert_resource(tp_cam::SettingsProfile(handle));
+    app.step(1);
+
+    let settings = app.world().resource::<tp_cam::ThirdPersonCameraSettings>();
+    assert_eq!(settings.default_camera_offset, Vec3::new(0.0, -2.0, -12.0));
+    assert_eq!(settings.pitch_max, 1.4);
+    assert_eq!(settings.up, tp_cam::ThirdPersonCameraSettings::default().up);
+}
+
+#[test]
+fn reloaded_profile_moves_the_camera() {
+    let CameraFixture {
+        mut app, camera, ..
+    } = camera_fixture().build();
+    let handle = load_profile(&mut app);
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::CameraProfile(handle.clone()));
+    app.step(1);
+    assert!((camera_distance(&app, camera) - Vec3::new(0.0, -2.0, -12.0).length()).abs() < 1e-4);
+
+    // Changed like a hot reload of the file
+    app.world_mut()
+        .resource_mut::<Assets<tp_cam::ThirdPersonCameraProfile>>()
+        .get_mut(&handle)
+        .unwrap()
+        .camera_offset = Some(Vec3::new(0.0, 0.0, -20.0));
+    app.step(2);
+    assert_eq!(
+        app.world().get::<tp_cam::CameraOffset>(camera).unwrap().0,
+        Vec3::new(0.0, 0.0, -20.0)
+    );
+    assert!((camera_distance(&app, camera) - 20.0).abs() < 1e-4);
+}
@@ -0,0 +1,72 @@
+use bevy::{math::cubic_splines::CubicBezier, prelude::*};
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn straight_curve() -> CubicCurve<Vec3> {
+    CubicBezier::new([[
+        Vec3::new(-9.0, 1.0, 10.0),
+        Vec3::new(-3.0, 1.0, 10.0),
+        Vec3::new(3.0, 1.0, 10.0),
+        Vec3::new(9.0, 1.0, 10.0),
+    ]])
+    .to_curve()
+    .unwrap()
+}
+
+fn rail_app(rail: tp_cam::CameraRail) -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture().camera(rail).step(0).build();
+    (app, camera)
+}
+
+#[test]
+fn camera_travels_along_rail() {
+    let mut rail = tp_cam::CameraRail::new(
+        straight_curve(),
+        tp_cam::RailParameterization::Time {
+            speed: 0.0,
+            looping: false,
+        },
+    );
+    rail.weight = 1.0;
+    let (mut app, camera) = rail_app(rail);
+    app.step(2);
+
+    assert_camera_at(&app, camera, Vec3::new(-9.0, 1.0, 10.0), 1e-4);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-4);
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<tp_cam::CameraRail>();
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn rail_follows_nearest_point() {
+    let (mut app, camera) = rail_app(tp_cam::CameraRail::new(
+        straight_curve(),
+        tp_cam::RailParameterization::NearestToTarget,
+    ));
+    app.step(120);
+
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 10.0), 0.2);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-3);
+}
+
+#[test]
+fn rail_blends_out_to_orbit() {
+    let mut rail = tp_cam::CameraRail::new(
+        straight_curve(),
+        tp_cam::RailParameterization::NearestToTarget,
+    );
+    rail.weight = 1.0;
+    rail.blend_out();
+    let (mut app, camera) = rail_app(rail);
+
+    app.step(30);
+    let blending = app.camera_transform(camera).translation;
+    assert!(blending.z > 10.0 && blending.z < 15.0, "{blending}");
+
+    app.step(60);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const FRAMES: u32 = 90;
+
+#[derive(Resource, Default)]
+struct Finished(Vec<Entity>);
+
+fn camera_app() -> (App, Entity, Entity) {
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = camera_fixture().camera(tp_cam::DampingFactor(8.0)).build();
+    (app, camera, target)
+}
+
+fn target_path(frame: u32) -> Vec3 {
+    let t = frame as f32 * 0.05;
+    Vec3::new(t.sin() * 4.0, 1.0, t * 2.0)
+}
+
+/// Moves the target along the same path in every run, `input` is called before each frame
+fn run(
+    app: &mut App,
+    camera: Entity,
+    target: Entity,
+    mut input: impl FnMut(&mut App, u32),
+) -> Vec<Transform> {
+    (0..FRAMES)
+        .map(|frame| {
+            app.move_to(target, target_path(frame));
+            input(app, frame);
+            app.step(1);
+            app.camera_transform(camera)
+        })
+        .collect()
+}
+
+fn player_input(app: &mut App, frame: u32) {
+    match frame {
+        5..=20 => {
+            app.move_mouse(Vec2::new(7.0, -2.5));
+        }
+        30 => {
+            app.scroll(3.0);
+        }
+        40 => {
+            app.press_key(KeyCode::KeyE);
+        }
+        50 => {
+            app.release_key(KeyCode::KeyE).scroll(-1.5);
+        }
+        60..=70 => {
+            app.move_mouse(Vec2::new(-3.0, 4.0));
+        }
+        _ => {}
+    }
+}
+
+fn record() -> (Vec<Transform>, tp_cam::CameraInputLog) {
+    let (mut app, camera, target) = camera_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::InputRecorder::default());
+    app.world_mut().trigger(tp_cam::SetLocalCamera(camera));
+    let poses = run(&mut app, camera, target, player_input);
+    let log = app
+        .world()
+        .get::<tp_cam::InputRecorder>(camera)
+        .unwrap()
+        .log
+        .clone();
+    (poses, log)
+}
+
+fn replay(log: tp_cam::CameraInputLog) -> Vec<Transform> {
+    let (mut app, camera, target) = camera_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::InputReplay::new(log));
+    run(&mut app, camera, target, |_, _| {})
+}
+
+/// Replay on the local camera while the player keeps playing
+fn replay_with_live_input(log: tp_cam::CameraInputLog) -> Vec<Transform> {
+    let (mut app, camera, target) = camera_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::InputReplay::new(log));
+    app.world_mut().trigger(tp_cam::SetLocalCamera(camera));
+    // The last recorded event is at frame 70, the input is live again after it
+    run(&mut app, camera, target, |app, frame| {
+        if frame < 60 {
+            app.move_mouse(Vec2::new(-5.0, 5.0)).scroll(1.0);
+        }
+    })
+}
+
+#[test]
+fn recording_captures_control_events() {
+    let (_, log) = record();
+
+    let count = |predicate: fn(&tp_cam::RecordedInput) -> bool| {
+        log.events.iter().filter(|e| predicate(&e.input)).count()
+    };
+    assert_eq!(count(|i| matches!(i, tp_cam::RecordedInput::Rotate(_))), 27);
+    assert_eq!(count(|i| matches!(i, tp_cam::RecordedInput::Zoom(_))), 2);
+    assert_eq!(count(|i| matches!(i, tp_cam::RecordedInput::Roll(_))), 10);
+    assert_eq!(
+        count(|i| matches!(i, tp_cam::RecordedInput::SetLocalCamera)),
+        1
+    );
+    assert!(log.events.is_sorted_by(|a, b| a.time <= b.time));
+}
+
+#[test]
+fn replay_reproduces_camera_poses() {
+    let (recorded, log) = record();
+    let replayed = replay(log);
+
+    assert_eq!(recorded, replayed);
+}
+
+#[test]
+fn live_input_is_ignored_during_replay() {
+    let (recorded, log) = record();
+    let replayed = replay_with_live_input(log);
+
+    assert_eq!(recorded, replayed);
+}
+
+#[test]
+fn replay_does_not_change_the_local_camera() {
+    let (_, log) = record();
+    let (mut app, camera, _) = camera_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::InputReplay::new(log));
+    app.step(FRAMES);
+
+    let settings = app.world().resource::<tp_cam::ThirdPersonCameraSettings>();
+    assert_eq!(settings.local_cam, None);
+}
+
+#[test]
+fn replay_finishes_after_last_event() {
+    let (_, log) = record();
+    let (mut app, camera, _) = camera_app();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::InputReplay::new(log));
+    app.init_resource::<Finished>().add_observer(
+        |finished_ev: On<tp_cam::ReplayFinished>, mut finished: ResMut<Finished>| {
+            finished.0.push(finished_ev.camera);
+        },
+    );
+    // The last recorded event is at frame 70
+    app.step(65);
+    assert!(app.world().resource::<Finished>().0.is_empty());
+
+    app.step(FRAMES - 65);
+    assert!(!app.world().entity(camera).contains::<tp_cam::InputReplay>());
+    assert_eq!(app.world().resource::<Finished>().0, [camera]);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn log_round_trips_through_ron() {
+    let (recorded, log) = record();
+    let ron = log.to_ron().unwrap();
+    let loaded = tp_cam::CameraInputLog::from_ron(&ron).unwrap();
+
+    assert_eq!(loaded, log);
+    assert_eq!(replay(loaded), recorded);
+}
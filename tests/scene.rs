@@ -0,0 +1,110 @@
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+    world_serialization::{DynamicWorld, DynamicWorldBuilder},
+};
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(2.0, 1.0, 0.0);
+
+fn saved_world() -> (DynamicWorld, Entity, Entity) {
+    let mut app = headless_app(tp_cam::ThirdPersonCameraSettings::default());
+    let target = app
+        .world_mut()
+        .spawn(Transform::from_translation(TARGET))
+        .id();
+    let camera = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(target),
+            tp_cam::CameraOffset(Vec3::new(0.0, -2.0, -10.0)),
+            tp_cam::TargetOffset(Vec3::Y),
+            tp_cam::DampingFactor(4.0),
+        ))
+        .id();
+    app.step(1);
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let dynamic_world = DynamicWorldBuilder::from_world(app.world(), &registry)
+        .extract_entities([camera, target].into_iter())
+        .build();
+    (dynamic_world, camera, target)
+}
+
+fn assert_loaded(dynamic_world: &DynamicWorld, camera: Entity, target: Entity) {
+    let mut app = headless_app(tp_cam::ThirdPersonCameraSettings::default());
+    let mut entity_map = EntityHashMap::default();
+    dynamic_world
+        .write_to_world(app.world_mut(), &mut entity_map)
+        .unwrap();
+    let (camera, target) = (entity_map[&camera], entity_map[&target]);
+
+    let world = app.world();
+    assert_eq!(
+        world.get::<tp_cam::ThirdPersonCamera>(camera),
+        Some(&tp_cam::ThirdPersonCamera::aimed_at(target))
+    );
+    assert_eq!(
+        world.get::<tp_cam::CameraOffset>(camera),
+        Some(&tp_cam::CameraOffset(Vec3::new(0.0, -2.0, -10.0)))
+    );
+    assert_eq!(
+        world.get::<tp_cam::TargetOffset>(camera),
+        Some(&tp_cam::TargetOffset(Vec3::Y))
+    );
+    assert_eq!(
+        world.get::<tp_cam::DampingFactor>(camera),
+        Some(&tp_cam::DampingFactor(4.0))
+    );
+    assert_eq!(
+        world
+            .get::<tp_cam::ThirdPersonCameraTarget>(target)
+            .map(|target| target.iter().collect::<Vec<_>>()),
+        Some(vec![camera])
+    );
+
+    app.step(1);
+    assert_camera_at(&app, camera, TARGET + Vec3::new(0.0, 3.0, 10.0), 1e-4);
+}
+
+#[test]
+fn camera_round_trips_through_dynamic_world() {
+    let (dynamic_world, camera, target) = saved_world();
+    assert_loaded(&dynamic_world, camera, target);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn camera_round_trips_through_ron() {
+    use bevy::{
+        asset::{AssetPath, LoadFromPath, UntypedHandle},
+        world_serialization::serde::WorldDeserializer,
+    };
+    use serde::de::DeserializeSeed;
+
+    struct NoAssets;
+    impl LoadFromPath for NoAssets {
+        fn load_from_path_erased(
+            &mut self,
+            _type_id: core::any::TypeId,
+            path: AssetPath<'static>,
+        ) -> UntypedHandle {
+            unreachable!("no assets in the saved cameras, got {path}")
+        }
+    }
+
+    let (dynamic_world, camera, target) = saved_world();
+    let app = headless_app(tp_cam::ThirdPersonCameraSettings::default());
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let ron = dynamic_world.serialize(&registry).unwrap();
+
+    let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+    let loaded_world = WorldDeserializer {
+        type_registry: &registry,
+        load_from_path: &mut NoAssets,
+    }
+    .deserialize(&mut deserializer)
+    .unwrap();
+    assert_loaded(&loaded_world, camera, target);
+}
@@ -0,0 +1,136 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+#[derive(Resource, Default)]
+struct Finished(Vec<bool>);
+
+fn sequence_app() -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture()
+        .local()
+        .setup(|app| {
+            app.init_resource::<Finished>().add_observer(
+                |finished_ev: On<tp_cam::SequenceFinished>, mut finished: ResMut<Finished>| {
+                    finished.0.push(finished_ev.skipped);
+                },
+            );
+        })
+        .build();
+    (app, camera)
+}
+
+/// Side view at 10 units, held from 0.16s to 0.48s, back to the player at 0.64s
+fn side_view() -> tp_cam::CameraSequence {
+    let state = tp_cam::OrbitState {
+        yaw: FRAC_PI_2,
+        pitch: 0.0,
+        distance: 10.0,
+        target_offset: Vec3::ZERO,
+        fov: None,
+    };
+    tp_cam::CameraSequence {
+        keyframes: vec![
+            tp_cam::CameraKeyframe {
+                time: 0.16,
+                state,
+                easing: EaseFunction::SmoothStep,
+            },
+            tp_cam::CameraKeyframe {
+                time: 0.48,
+                state,
+                easing: EaseFunction::Linear,
+            },
+        ],
+        return_duration: 0.16,
+        return_easing: Some(EaseFunction::SmoothStep),
+    }
+}
+
+fn play(app: &mut App, camera: Entity) {
+    app.world_mut().trigger(tp_cam::PlaySequence {
+        camera,
+        sequence: side_view(),
+    });
+}
+
+#[test]
+fn sequence_plays_and_returns() {
+    let (mut app, camera) = sequence_app();
+    play(&mut app, camera);
+
+    app.step(20);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 1.0, 0.0), 1e-3);
+    assert_camera_looks_at(&app, camera, TARGET, 1e-3);
+
+    app.step(25);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+    assert!(
+        !app.world()
+            .entity(camera)
+            .contains::<tp_cam::SequencePlayer>()
+    );
+    assert_eq!(app.world().resource::<Finished>().0, [false]);
+}
+
+#[test]
+fn player_input_ignored_during_sequence() {
+    // The same input moves the camera without a sequence
+    let (mut free_app, free_camera) = sequence_app();
+    free_app
+        .move_mouse(Vec2::new(200.0, 50.0))
+        .scroll(5.0)
+        .step(20);
+    assert!(
+        free_app
+            .camera_transform(free_camera)
+            .translation
+            .distance(Vec3::new(0.0, 1.0, 15.0))
+            > 1.0
+    );
+
+    let (mut app, camera) = sequence_app();
+    play(&mut app, camera);
+
+    app.move_mouse(Vec2::new(200.0, 50.0)).scroll(5.0).step(20);
+    assert_camera_at(&app, camera, Vec3::new(10.0, 1.0, 0.0), 1e-3);
+
+    app.step(25);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn pause_and_skip_sequence() {
+    let (mut app, camera) = sequence_app();
+    play(&mut app, camera);
+    app.step(5);
+
+    app.world_mut().trigger(tp_cam::PauseSequence {
+        camera,
+        paused: true,
+    });
+    let paused_at = app.camera_transform(camera).translation;
+    app.step(10);
+    assert_camera_at(&app, camera, paused_at, 1e-5);
+
+    app.world_mut().trigger(tp_cam::SkipSequence { camera });
+    app.step(1);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+    assert_eq!(app.world().resource::<Finished>().0, [true]);
+}
+
+#[test]
+fn yaw_turns_along_shortest_arc() {
+    let state = |yaw| tp_cam::OrbitState {
+        yaw,
+        pitch: 0.0,
+        distance: 10.0,
+        target_offset: Vec3::ZERO,
+        fov: None,
+    };
+
+    let middle = state(3.0).lerp(&state(-3.0), 0.5);
+    assert!((middle.yaw.abs() - PI).abs() < 1e-5, "{}", middle.yaw);
+}
@@ -0,0 +1,134 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const TARGET: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+#[derive(Resource, Default)]
+struct Zoomed(Vec<f32>);
+
+/// Up to 0.5 radians of FOV and 5 units of distance at 20 units per second
+fn speed_app() -> (App, Entity, Entity) {
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = speed_fixture().build();
+    (app, camera, target)
+}
+
+fn speed_fixture() -> CameraFixtureBuilder {
+    camera_fixture()
+        .camera((
+            Projection::Perspective(default()),
+            tp_cam::SpeedEffects::new(
+                tp_cam::SpeedCurve::ramp(0.0, 20.0, 0.5),
+                tp_cam::SpeedCurve::ramp(5.0, 20.0, 5.0),
+            ),
+        ))
+        .step(2)
+}
+
+fn fov(app: &App, camera: Entity) -> f32 {
+    match app.world().get::<Projection>(camera).unwrap() {
+        Projection::Perspective(perspective) => perspective.fov,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn curve_is_piecewise_linear() {
+    let curve = tp_cam::SpeedCurve(vec![
+        Vec2::new(0.0, 1.0),
+        Vec2::new(10.0, 2.0),
+        Vec2::new(20.0, 0.0),
+    ]);
+
+    assert_eq!(curve.sample(-5.0), 1.0);
+    assert_eq!(curve.sample(5.0), 1.5);
+    assert_eq!(curve.sample(15.0), 1.0);
+    assert_eq!(curve.sample(30.0), 0.0);
+    assert_eq!(tp_cam::SpeedCurve::default().sample(10.0), 0.0);
+}
+
+#[test]
+fn fast_target_widens_fov_and_distance() {
+    let (mut app, camera, target) = speed_app();
+    assert_eq!(fov(&app, camera), FRAC_PI_4);
+
+    let mut position = TARGET;
+    for _ in 0..180 {
+        position.x += 20.0 * FRAME_TIME.as_secs_f32();
+        app.move_to(target, position).step(1);
+    }
+
+    assert!((fov(&app, camera) - (FRAC_PI_4 + 0.5)).abs() < 1e-3);
+    assert!((camera_distance(&app, camera) - 20.0).abs() < 1e-2);
+}
+
+#[test]
+fn modifiers_are_removed_when_stopped() {
+    let (mut app, camera, target) = speed_app();
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::X * 12.5));
+    app.step(240);
+    assert!((camera_distance(&app, camera) - 17.5).abs() < 1e-2);
+
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::ZERO));
+    app.step(240);
+    assert!((fov(&app, camera) - FRAC_PI_4).abs() < 1e-5);
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-4);
+}
+
+#[test]
+fn removing_effects_restores_camera() {
+    let (mut app, camera, target) = speed_app();
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::X * 20.0));
+    app.step(120);
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<tp_cam::SpeedEffects>();
+    app.step(1);
+    assert!((fov(&app, camera) - FRAC_PI_4).abs() < 1e-5);
+    assert!((camera_distance(&app, camera) - 15.0).abs() < 1e-4);
+}
+
+#[test]
+fn base_offset_and_zoom_exclude_the_effects() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = speed_fixture().local().build();
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::X * 20.0));
+    app.step(240);
+    assert_eq!(
+        app.world().get::<tp_cam::CameraOffset>(camera).unwrap().0,
+        Vec3::new(0.0, 0.0, -15.0)
+    );
+
+    app.init_resource::<Zoomed>().add_observer(
+        |zoomed_ev: On<tp_cam::CameraZoomed>, mut zoomed: ResMut<Zoomed>| {
+            zoomed.0.push(zoomed_ev.distance);
+        },
+    );
+    app.scroll(-1.0).step(1);
+    let distance = app.world().resource::<Zoomed>().0[0];
+    assert!((camera_distance(&app, camera) - (distance + 5.0)).abs() < 1e-2);
+
+    app.world_mut()
+        .entity_mut(target)
+        .insert(tp_cam::TargetVelocity(Vec3::ZERO));
+    app.step(240);
+    assert!((fov(&app, camera) - FRAC_PI_4).abs() < 1e-5);
+    assert!((camera_distance(&app, camera) - distance).abs() < 1e-4);
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+#[derive(Resource, Default)]
+struct Lost(Vec<(Entity, Entity)>);
+
+fn record_lost(app: &mut App) {
+    app.init_resource::<Lost>().add_observer(
+        |lost_ev: On<tp_cam::TargetLost>, mut lost: ResMut<Lost>| {
+            lost.0.push((lost_ev.camera, lost_ev.target));
+        },
+    );
+}
+
+fn aimed_target(app: &App, camera: Entity) -> Option<Entity> {
+    app.world()
+        .get::<tp_cam::ThirdPersonCamera>(camera)
+        .map(|tp_camera| tp_camera.target)
+}
+
+#[test]
+fn camera_waits_for_target_transform() {
+    let mut app = headless_app(tp_cam::ThirdPersonCameraSettings::default());
+    let target = app.world_mut().spawn_empty().id();
+    let camera = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            tp_cam::ThirdPersonCamera::aimed_at(target),
+        ))
+        .id();
+    app.step(2);
+    assert!(app.world().get::<tp_cam::TargetPoint>(camera).is_none());
+
+    app.world_mut()
+        .entity_mut(target)
+        .insert(Transform::from_xyz(5.0, 0.0, 0.0));
+    app.step(1);
+
+    assert_camera_at(&app, camera, Vec3::new(5.0, 0.0, 15.0), 1e-4);
+}
+
+#[test]
+fn target_loss_holds_position() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().setup(record_lost).build();
+    app.move_to(target, Vec3::new(3.0, 1.0, 0.0)).step(2);
+
+    app.world_mut().despawn(target);
+    app.step(5);
+
+    assert_eq!(app.world().resource::<Lost>().0, vec![(camera, target)]);
+    assert_eq!(aimed_target(&app, camera), None);
+    assert_camera_at(&app, camera, Vec3::new(3.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn target_loss_falls_back() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().setup(record_lost).step(0).build();
+    let fallback = app
+        .world_mut()
+        .spawn(Transform::from_xyz(-5.0, 0.0, 0.0))
+        .id();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::TargetLossPolicy::Fallback(fallback));
+    app.step(1);
+
+    app.world_mut().despawn(target);
+    app.step(2);
+
+    assert_eq!(app.world().resource::<Lost>().0, vec![(camera, target)]);
+    assert_eq!(aimed_target(&app, camera), Some(fallback));
+    assert_camera_at(&app, camera, Vec3::new(-5.0, 0.0, 15.0), 1e-4);
+}
+
+#[test]
+fn default_policy_from_settings() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().step(0).build();
+    let fallback = app
+        .world_mut()
+        .spawn(Transform::from_xyz(-5.0, 0.0, 0.0))
+        .id();
+    app.world_mut()
+        .resource_mut::<tp_cam::ThirdPersonCameraSettings>()
+        .default_target_loss_policy = tp_cam::TargetLossPolicy::Fallback(fallback);
+    app.step(1);
+
+    app.world_mut().despawn(target);
+    app.step(2);
+
+    assert_eq!(aimed_target(&app, camera), Some(fallback));
+}
+
+#[test]
+fn missing_fallback_holds_position() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().setup(record_lost).step(0).build();
+    let fallback = app.world_mut().spawn(Transform::default()).id();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::TargetLossPolicy::Fallback(fallback));
+    app.step(1);
+
+    app.world_mut().despawn(fallback);
+    app.world_mut().despawn(target);
+    app.step(2);
+
+    assert_eq!(app.world().resource::<Lost>().0, vec![(camera, target)]);
+    assert_eq!(aimed_target(&app, camera), None);
+    assert_camera_at(&app, camera, Vec3::new(0.0, 1.0, 15.0), 1e-4);
+}
+
+#[test]
+fn fallback_lost_in_turn() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture().setup(record_lost).step(0).build();
+    let fallback = app
+        .world_mut()
+        .spawn(Transform::from_xyz(-5.0, 0.0, 0.0))
+        .id();
+    app.world_mut()
+        .entity_mut(camera)
+        .insert(tp_cam::TargetLossPolicy::Fallback(fallback));
+    app.step(1);
+
+    app.world_mut().despawn(target);
+    app.step(2);
+    app.world_mut().despawn(fallback);
+    app.step(2);
+
+    assert_eq!(
+        app.world().resource::<Lost>().0,
+        vec![(camera, target), (camera, fallback)]
+    );
+    assert_eq!(aimed_target(&app, camera), None);
+    assert_camera_at(&app, camera, Vec3::new(-5.0, 0.0, 15.0), 1e-4);
+}
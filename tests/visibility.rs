@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+#[derive(Resource, Default)]
+struct Changes(Vec<tp_cam::TargetVisibility>);
+
+/// Camera at (0, 1, 15) aimed at a target at (0, 1, 0)
+fn visibility_app() -> (App, Entity) {
+    let CameraFixture { app, camera, .. } = camera_fixture()
+        .setup(|app| {
+            app.init_resource::<Changes>().add_observer(
+                |changed_ev: On<tp_cam::TargetVisibilityChanged>, mut changes: ResMut<Changes>| {
+                    changes.0.push(changed_ev.visibility);
+                },
+            );
+        })
+        .target(tp_cam::Occluder(tp_cam::OccluderShape::Sphere {
+            radius: 1.0,
+        }))
+        .camera((
+            Projection::Perspective(default()),
+            tp_cam::TargetVisibility::default(),
+        ))
+        .build();
+    (app, camera)
+}
+
+fn visibility(app: &App, camera: Entity) -> tp_cam::TargetVisibility {
+    *app.world().get::<tp_cam::TargetVisibility>(camera).unwrap()
+}
+
+fn spawn_occluder(app: &mut App, shape: tp_cam::OccluderShape) {
+    app.world_mut()
+        .spawn((Transform::from_xyz(0.0, 1.0, 7.0), tp_cam::Occluder(shape)));
+}
+
+#[test]
+fn target_is_visible() {
+    let (app, camera) = visibility_app();
+
+    assert_eq!(visibility(&app, camera), tp_cam::TargetVisibility::Visible);
+    assert!(app.world().resource::<Changes>().0.is_empty());
+}
+
+#[test]
+fn small_occluder_partially_occludes() {
+    let (mut app, camera) = visibility_app();
+    spawn_occluder(&mut app, tp_cam::OccluderShape::Sphere { radius: 0.2 });
+    app.step(2);
+
+    assert_eq!(
+        visibility(&app, camera),
+        tp_cam::TargetVisibility::PartiallyOccluded
+    );
+}
+
+#[test]
+fn wall_occludes_and_triggers_change() {
+    let (mut app, camera) = visibility_app();
+    spawn_occluder(
+        &mut app,
+        tp_cam::OccluderShape::Aabb {
+            half_size: Vec3::new(3.0, 3.0, 0.1),
+        },
+    );
+    app.step(2);
+
+    assert_eq!(visibility(&app, camera), tp_cam::TargetVisibility::Occluded);
+    assert_eq!(
+        app.world().resource::<Changes>().0,
+        [tp_cam::TargetVisibility::Occluded]
+    );
+}
+
+#[test]
+fn custom_line_of_sight_backend() {
+    let (mut app, camera) = visibility_app();
+    // Everything below y = 1 is blocked
+    app.insert_resource(tp_cam::LineOfSightQuery::new(
+        |_: Vec3, to: Vec3, _: Entity| to.y < 1.0 - 1e-3,
+    ));
+    app.step(1);
+
+    assert_eq!(
+        visibility(&app, camera),
+        tp_cam::TargetVisibility::PartiallyOccluded
+    );
+}
+
+#[test]
+fn target_off_screen() {
+    let (mut app, camera) = visibility_app();
+    app.world_mut().trigger(tp_cam::DetachCamera { camera });
+    app.world_mut().trigger(tp_cam::RotateAroundTarget {
+        camera,
+        delta: Vec2::new(300.0, 0.0),
+    });
+    app.step(1);
+
+    assert_eq!(
+        visibility(&app, camera),
+        tp_cam::TargetVisibility::OffScreen
+    );
+}
+
+#[test]
+fn target_off_screen_of_orthographic_camera() {
+    let CameraFixture {
+        mut app,
+        camera,
+        target,
+    } = camera_fixture()
+        .camera((
+            Transform::from_xyz(0.0, 0.0, 100.0),
+            Projection::Orthographic(OrthographicProjection {
+                area: Rect::new(-5.0, -3.0, 5.0, 3.0),
+                ..OrthographicProjection::default_2d()
+            }),
+            tp_cam::Follow2d {
+                bounds: Some(Rect::new(-20.0, -10.0, 20.0, 10.0)),
+                ..default()
+            },
+            tp_cam::TargetVisibility::default(),
+        ))
+        .step(2)
+        .build();
+    assert_eq!(visibility(&app, camera), tp_cam::TargetVisibility::Visible);
+
+    // The view stops at the bounds
+    app.move_to(target, Vec3::new(30.0, 0.0, 0.0)).step(2);
+    assert_eq!(
+        visibility(&app, camera),
+        tp_cam::TargetVisibility::OffScreen
+    );
+}
@@ -0,0 +1,132 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy_third_person_camera_2::{self as tp_cam, testing::*};
+
+const OUTSIDE: Vec3 = Vec3::new(20.0, 1.0, 0.0);
+const INSIDE: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn volume_app(volumes: impl IntoIterator<Item = tp_cam::CameraVolume>) -> (App, Entity, Entity) {
+    let volumes: Vec<_> = volumes.into_iter().collect();
+    let CameraFixture {
+        app,
+        camera,
+        target,
+    } = camera_fixture()
+        .setup(|app| {
+            for volume in volumes {
+                app.world_mut().spawn((Transform::default(), volume));
+            }
+        })
+        .target(Transform::from_translation(OUTSIDE))
+        .step(2)
+        .build();
+    (app, camera, target)
+}
+
+fn box_volume(overrides: tp_cam::CameraOverrides) -> tp_cam::CameraVolume {
+    tp_cam::CameraVolume {
+        blend_in: 0.32,
+        blend_out: 0.32,
+        ..tp_cam::CameraVolume::new(
+            tp_cam::VolumeShape::Aabb {
+                half_size: Vec3::splat(5.0),
+            },
+            overrides,
+        )
+    }
+}
+
+fn yaw_pitch(app: &App, camera: Entity) -> (f32, f32) {
+    let (yaw, pitch, _) = app
+        .camera_transform(camera)
+        .rotation
+        .to_euler(EulerRot::YXZ);
+    (yaw, pitch)
+}
+
+#[test]
+fn volume_blends_in_and_out() {
+    let (mut app, camera, target) = volume_app([box_volume(tp_cam::CameraOverrides {
+        distance: Some(5.0),
+        yaw: Some(FRAC_PI_2),
+        pitch: Some(-0.5),
+        ..default()
+    })]);
+
+    app.move_to(target, INSIDE).step(10);
+    let blending = camera_distance(&app, camera);
+    assert!(blending > 5.0 && blending < 15.0, "{blending}");
+
+    app.step(20);
+    assert!((camera_distance(&app, camera) - 5.0).abs() < 1e-4);
+    let (yaw, pitch) = yaw_pitch(&app, camera);
+    assert!((yaw - FRAC_PI_2).abs() < 1e-4 && (pitch + 0.5).abs() < 1e-4);
+    assert_camera_looks_at(&app, camera, INSIDE, 1e-4);
+
+    app.move_to(target, OUTSIDE).step(30);
+    assert_camera_at(&app, camera, OUTSIDE + Vec3::Z * 15.0, 1e-3);
+}
+
+#[test]
+fn highest_priority_applies_last() {
+    let (mut app, camera, target) = volume_app([
+        tp_cam::CameraVolume {
+            priority: 1,
+            ..box_volume(tp_cam::CameraOverrides {
+                distance: Some(5.0),
+                ..default()
+            })
+        },
+        box_volume(tp_cam::CameraOverrides {
+            distance: Some(8.0),
+            target_offset: Some(Vec3::Y),
+            ..default()
+        }),
+    ]);
+
+    app.move_to(target, INSIDE).step(30);
+    // The target offset of the volume moves the point the camera orbits
+    let orbit_center = INSIDE + Vec3::Y;
+    let distance = app
+        .camera_transform(camera)
+        .translation
+        .distance(orbit_center);
+    assert!((distance - 5.0).abs() < 1e-4);
+    assert_camera_looks_at(&app, camera, orbit_center, 1e-4);
+}
+
+#[test]
+fn yaw_limits_clamp_player_yaw() {
+    let (mut app, camera, target) = volume_app([box_volume(tp_cam::CameraOverrides {
+        yaw_limits: Some((0.5, 1.0)),
+        ..default()
+    })]);
+
+    app.move_to(target, INSIDE).step(30);
+    assert!((yaw_pitch(&app, camera).0 - 0.5).abs() < 1e-4);
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<tp_cam::VolumeBlend>();
+    assert!(yaw_pitch(&app, camera).0.abs() < 1e-4);
+}
+
+#[test]
+fn yaw_limits_across_pi() {
+    // Behind the target, the player's yaw 0 is outside and closer to the maximum
+    let (mut app, camera, target) = volume_app([box_volume(tp_cam::CameraOverrides {
+        yaw_limits: Some((2.5, -2.0)),
+        ..default()
+    })]);
+
+    app.move_to(target, INSIDE).step(30);
+    assert!((yaw_pitch(&app, camera).0 + 2.0).abs() < 1e-4);
+
+    // Inside the range across ±π the yaw is kept
+    app.world_mut().trigger(tp_cam::SetLocalCamera(camera));
+    app.move_mouse(Vec2::new(600.0, 0.0));
+    app.step(1);
+    let (yaw, _) = yaw_pitch(&app, camera);
+    assert!((yaw + 3.0).abs() < 1e-4, "{yaw}");
+}